@@ -1,15 +1,36 @@
 use std::env;
 
 include!("src/load.rs");
+include!("src/manifest.rs");
 
-fn main() -> Result<()> {
+fn main() -> io::Result<()> {
     println!("cargo:rerun-if-changed=assets/oid_db.txt");
+    println!("cargo:rerun-if-changed=Cargo.toml");
 
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join("oid_db.rs");
+    let tables_path = Path::new(&out_dir).join("oid_tables.rs");
 
-    let m = load_file("assets/oid_db.txt")?;
+    let m = load_file("assets/oid_db.txt").unwrap_or_else(|e| panic!("{}", e));
+    let manifest = std::fs::read_to_string("Cargo.toml")?;
+    let features = declared_features(&manifest);
+    let features: Vec<_> = features.iter().map(String::as_str).collect();
+    let (errors, warnings): (Vec<_>, Vec<_>) = validate(&m, Some(&features)).into_iter().partition(Diagnostic::is_fatal);
+    for warning in warnings {
+        println!("cargo:warning={}", warning);
+    }
+    if !errors.is_empty() {
+        let errors: Vec<_> = errors.iter().map(Diagnostic::to_string).collect();
+        panic!("invalid OID database:\n{}", errors.join("\n"));
+    }
     generate_file(&m, dest_path)?;
+    generate_tables(&m, tables_path)?;
+
+    // code used by tests, to check that strings are escaped correctly
+    println!("cargo:rerun-if-changed=assets/tests/escaping.txt");
+    let m = load_file("assets/tests/escaping.txt").unwrap_or_else(|e| panic!("{}", e));
+    generate_file(&m, Path::new(&out_dir).join("escaping_db.rs"))?;
+    generate_tables(&m, Path::new(&out_dir).join("escaping_tables.rs"))?;
 
     Ok(())
 }
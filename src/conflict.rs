@@ -0,0 +1,205 @@
+//! Conflict resolution when inserting entries or merging registries
+
+use crate::{Entry, Oid, OidEntry, OidRegistry, OidRegistryError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Policy used to resolve conflicts, when inserting an OID which is already registered with a
+/// different entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Keep the existing entry, and discard the new one
+    KeepExisting,
+    /// Replace the existing entry by the new one
+    Overwrite,
+    /// Return an error, leaving the registry unchanged
+    Error,
+    /// Keep the existing entry, and add the short name and aliases of the new one as aliases
+    KeepBothAsAlias,
+}
+
+/// A conflict detected (and resolved) when inserting an entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict<'a> {
+    /// The OID registered with different entries
+    pub oid: Oid<'a>,
+    /// The short name of the entry registered before the insertion
+    pub existing_sn: String,
+    /// The short name of the inserted entry
+    pub incoming_sn: String,
+    /// The entry which is not stored in the registry anymore
+    ///
+    /// This is the existing entry for the `Overwrite` policy, and the inserted entry otherwise.
+    pub discarded: OidEntry,
+}
+
+impl<'a> OidRegistry<'a> {
+    /// Insert a new entry, using `policy` if the OID is already registered with a different entry
+    ///
+    /// Returns the resolved conflict, if any. If the policy is `ConflictPolicy::Error`, a conflict
+    /// is returned as an error and the registry is not modified.
+    ///
+    /// Inserting an entry equal to the registered one is not a conflict.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::{ConflictPolicy, OidRegistry};
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.2.3.4), ("shortName", "A description"));
+    ///
+    /// let res = registry.try_insert(oid!(1.2.3.4), ("otherName", "Another description"), ConflictPolicy::Error);
+    /// assert!(res.is_err());
+    ///
+    /// let conflict = registry
+    ///     .try_insert(oid!(1.2.3.4), ("otherName", "Another description"), ConflictPolicy::KeepBothAsAlias)
+    ///     .expect("no error")
+    ///     .expect("no conflict");
+    /// assert_eq!(conflict.incoming_sn, "otherName");
+    /// assert!(registry.get_by_sn("otherName").unwrap().is_some());
+    /// ```
+    pub fn try_insert<E>(&mut self, oid: Oid<'a>, entry: E, policy: ConflictPolicy) -> Result<Option<Conflict<'a>>, OidRegistryError>
+    where
+        E: Into<OidEntry>,
+    {
+        let entry = entry.into();
+        let mut occupied = match self.entry(oid) {
+            Entry::Occupied(e) => e,
+            Entry::Vacant(e) => {
+                e.insert(entry);
+                return Ok(None);
+            }
+        };
+        let existing = occupied.get();
+        if *existing == entry {
+            return Ok(None);
+        }
+        let existing_sn = existing.sn().to_string();
+        let incoming_sn = entry.sn().to_string();
+        let discarded = match policy {
+            ConflictPolicy::Error => {
+                return Err(OidRegistryError::Conflict {
+                    oid: occupied.key().to_owned(),
+                    existing_sn,
+                    incoming_sn,
+                })
+            }
+            ConflictPolicy::KeepExisting => entry,
+            ConflictPolicy::Overwrite => occupied.insert(entry),
+            ConflictPolicy::KeepBothAsAlias => {
+                occupied.modify(|e| {
+                    for name in entry.names() {
+                        e.add_alias(name.clone());
+                    }
+                });
+                entry
+            }
+        };
+        Ok(Some(Conflict {
+            oid: occupied.key().clone(),
+            existing_sn,
+            incoming_sn,
+            discarded,
+        }))
+    }
+
+    /// Merge all entries of `other` into this registry, using `policy` to resolve conflicts
+    ///
+    /// Returns the list of resolved conflicts. If the policy is `ConflictPolicy::Error` and there
+    /// is at least one conflict, the first conflict is returned as an error and the registry is
+    /// not modified.
+    ///
+    /// This can be used to add a set of known OIDs without overwriting the existing entries:
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::{ConflictPolicy, OidRegistry};
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.5.4.3), ("cn", "My common name"));
+    ///
+    /// let other = OidRegistry::default()
+    /// # ;
+    /// # #[cfg(feature = "x509")]
+    /// # let other = other
+    ///     .with_x509(); // only if the 'x509' feature is enabled
+    /// let conflicts = registry.merge(other, ConflictPolicy::KeepExisting).expect("merge failed");
+    /// for conflict in &conflicts {
+    ///     println!("{}: kept {}, discarded {}", conflict.oid, conflict.existing_sn, conflict.incoming_sn);
+    /// }
+    /// ```
+    pub fn merge(&mut self, other: OidRegistry<'a>, policy: ConflictPolicy) -> Result<Vec<Conflict<'a>>, OidRegistryError> {
+        if policy == ConflictPolicy::Error {
+            for (oid, entry) in other.iter() {
+                match self.get(oid) {
+                    Some(existing) if existing != entry => {
+                        return Err(OidRegistryError::Conflict {
+                            oid: oid.to_owned(),
+                            existing_sn: existing.sn().to_string(),
+                            incoming_sn: entry.sn().to_string(),
+                        });
+                    }
+                    _ => (),
+                }
+            }
+        }
+        let mut conflicts = Vec::new();
+        for (key, entry) in other.map {
+            if let Some(conflict) = self.try_insert(key.0, entry, policy)? {
+                conflicts.push(conflict);
+            }
+        }
+        Ok(conflicts)
+    }
+}
+
+#[rustfmt::skip::macros(oid)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    fn base() -> OidRegistry<'static> {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.4), ("a", "A"));
+        registry.insert(oid!(1.2.3.5), ("b", "B"));
+        registry
+    }
+
+    fn other() -> OidRegistry<'static> {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.4), ("a", "A"));
+        registry.insert(oid!(1.2.3.5), ("c", "C"));
+        registry.insert(oid!(1.2.3.6), ("d", "D"));
+        registry
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut registry = base();
+        let conflicts = registry.merge(other(), ConflictPolicy::KeepExisting).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].oid, oid!(1.2.3.5));
+        assert_eq!(conflicts[0].discarded.sn(), "c");
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get(&oid!(1.2.3.5)).map(|e| e.sn()), Some("b"));
+
+        let mut registry = base();
+        let conflicts = registry.merge(other(), ConflictPolicy::Overwrite).unwrap();
+        assert_eq!(conflicts[0].discarded.sn(), "b");
+        assert_eq!(registry.get(&oid!(1.2.3.5)).map(|e| e.sn()), Some("c"));
+        assert_eq!(registry.iter_by_sn("b").count(), 0);
+
+        let mut registry = base();
+        let err = registry.merge(other(), ConflictPolicy::Error).unwrap_err();
+        assert!(matches!(err, OidRegistryError::Conflict { .. }));
+        assert_eq!(registry.len(), 2);
+
+        let mut registry = base();
+        registry.merge(other(), ConflictPolicy::KeepBothAsAlias).unwrap();
+        let entry = registry.get(&oid!(1.2.3.5)).unwrap();
+        assert_eq!(entry.sn(), "b");
+        assert_eq!(entry.aliases().collect::<Vec<_>>(), ["c"]);
+        assert_eq!(registry.get_by_sn("c").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.5)));
+    }
+}
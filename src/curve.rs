@@ -0,0 +1,322 @@
+//! Properties of elliptic curves
+
+use crate::static_registry::EC_CURVES;
+use asn1_rs::{Any, Class, Oid, Tag};
+
+/// Form of the equation of an elliptic curve
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CurveForm {
+    /// Short Weierstrass form: y² = x³ + ax + b (for ex. P-256)
+    ShortWeierstrass,
+    /// (Twisted) Edwards form: ax² + y² = 1 + dx²y² (for ex. Ed25519)
+    Edwards,
+    /// Montgomery form: By² = x³ + Ax² + x (for ex. X25519)
+    Montgomery,
+}
+
+/// Properties of an elliptic curve
+///
+/// Curves are either identified by a named curve OID (for ex. `OID_EC_P256`, used as parameter
+/// of `id-ecPublicKey`), or implied by the algorithm OID (for ex. `OID_SIG_ED25519`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EcCurveInfo {
+    pub(crate) name: &'static str,
+    pub(crate) aliases: &'static [&'static str],
+    pub(crate) field_bits: usize,
+    pub(crate) form: CurveForm,
+    pub(crate) security_bits: usize,
+}
+
+impl EcCurveInfo {
+    /// Get the short name of the curve OID (for ex. `prime256v1`)
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the other known names of the curve (for ex. the SECG name `secp256r1` and the NIST
+    /// name `P-256`)
+    #[inline]
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Get the size of the underlying field, in bits
+    #[inline]
+    pub const fn field_bits(&self) -> usize {
+        self.field_bits
+    }
+
+    /// Get the form of the curve equation
+    #[inline]
+    pub const fn form(&self) -> CurveForm {
+        self.form
+    }
+
+    /// Get the approximate security level of the curve, in bits
+    #[inline]
+    pub const fn security_bits(&self) -> usize {
+        self.security_bits
+    }
+}
+
+/// Get the properties of an elliptic curve
+///
+/// Returns `None` if the curve is unknown, or if its feature is not enabled.
+///
+/// ```rust
+/// use oid_registry::{ec_curve_info, CurveForm};
+///
+/// # #[cfg(feature = "x962")] {
+/// let info = ec_curve_info(&oid_registry::OID_EC_P256).expect("unknown curve");
+/// assert_eq!(info.field_bits(), 256);
+/// assert_eq!(info.form(), CurveForm::ShortWeierstrass);
+/// assert!(info.aliases().contains(&"P-256"));
+/// # }
+/// ```
+pub fn ec_curve_info(oid: &Oid) -> Option<&'static EcCurveInfo> {
+    ec_curve_info_by_der(oid.as_bytes())
+}
+
+fn ec_curve_info_by_der(der: &[u8]) -> Option<&'static EcCurveInfo> {
+    EC_CURVES.iter().find(|(o, _)| o.as_bytes() == der).map(|(_, info)| info)
+}
+
+/// Get the named curve from the parameters of an `id-ecPublicKey` `AlgorithmIdentifier`
+///
+/// The parameters are expected to be a `namedCurve` OID, as required by RFC 5480. Returns `None`
+/// if the parameters are not an OID, or if the curve is unknown.
+pub fn named_curve(parameters: &Any) -> Option<&'static EcCurveInfo> {
+    if parameters.class() != Class::Universal || parameters.tag() != Tag::Oid {
+        return None;
+    }
+    ec_curve_info_by_der(parameters.data)
+}
+
+/// Domain parameters of an elliptic curve in short Weierstrass form
+///
+/// All values are unsigned big-endian integers, padded to the size of the field.
+#[cfg(feature = "ec_params")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ec_params")))]
+#[derive(Debug, PartialEq, Eq)]
+pub struct EcDomainParameters {
+    p: &'static [u8],
+    a: &'static [u8],
+    b: &'static [u8],
+    gx: &'static [u8],
+    gy: &'static [u8],
+    n: &'static [u8],
+    h: u32,
+}
+
+#[cfg(feature = "ec_params")]
+impl EcDomainParameters {
+    /// Get the prime `p` of the field
+    #[inline]
+    pub const fn p(&self) -> &'static [u8] {
+        self.p
+    }
+
+    /// Get the coefficient `a` of the curve equation
+    #[inline]
+    pub const fn a(&self) -> &'static [u8] {
+        self.a
+    }
+
+    /// Get the coefficient `b` of the curve equation
+    #[inline]
+    pub const fn b(&self) -> &'static [u8] {
+        self.b
+    }
+
+    /// Get the coordinates `(x, y)` of the base point `G`
+    #[inline]
+    pub const fn generator(&self) -> (&'static [u8], &'static [u8]) {
+        (self.gx, self.gy)
+    }
+
+    /// Get the order `n` of the base point
+    #[inline]
+    pub const fn order(&self) -> &'static [u8] {
+        self.n
+    }
+
+    /// Get the cofactor `h`
+    #[inline]
+    pub const fn cofactor(&self) -> u32 {
+        self.h
+    }
+}
+
+/// Get the domain parameters of a named curve
+///
+/// Domain parameters are only available for the NIST prime curves (P-256, P-384 and P-521).
+#[cfg(feature = "ec_params")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ec_params")))]
+pub fn ec_domain_parameters(oid: &Oid) -> Option<&'static EcDomainParameters> {
+    use crate::{OID_EC_P256, OID_NIST_EC_P384, OID_NIST_EC_P521};
+
+    if oid == &OID_EC_P256 {
+        Some(&P256)
+    } else if oid == &OID_NIST_EC_P384 {
+        Some(&P384)
+    } else if oid == &OID_NIST_EC_P521 {
+        Some(&P521)
+    } else {
+        None
+    }
+}
+
+// Domain parameters, from SEC 2 / FIPS 186-4
+#[cfg(feature = "ec_params")]
+static P256: EcDomainParameters = EcDomainParameters {
+    p: &[
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ],
+    a: &[
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+    ],
+    b: &[
+        0x5a, 0xc6, 0x35, 0xd8, 0xaa, 0x3a, 0x93, 0xe7, 0xb3, 0xeb, 0xbd, 0x55, 0x76, 0x98, 0x86, 0xbc, 0x65, 0x1d, 0x06, 0xb0, 0xcc, 0x53,
+        0xb0, 0xf6, 0x3b, 0xce, 0x3c, 0x3e, 0x27, 0xd2, 0x60, 0x4b,
+    ],
+    gx: &[
+        0x6b, 0x17, 0xd1, 0xf2, 0xe1, 0x2c, 0x42, 0x47, 0xf8, 0xbc, 0xe6, 0xe5, 0x63, 0xa4, 0x40, 0xf2, 0x77, 0x03, 0x7d, 0x81, 0x2d, 0xeb,
+        0x33, 0xa0, 0xf4, 0xa1, 0x39, 0x45, 0xd8, 0x98, 0xc2, 0x96,
+    ],
+    gy: &[
+        0x4f, 0xe3, 0x42, 0xe2, 0xfe, 0x1a, 0x7f, 0x9b, 0x8e, 0xe7, 0xeb, 0x4a, 0x7c, 0x0f, 0x9e, 0x16, 0x2b, 0xce, 0x33, 0x57, 0x6b, 0x31,
+        0x5e, 0xce, 0xcb, 0xb6, 0x40, 0x68, 0x37, 0xbf, 0x51, 0xf5,
+    ],
+    n: &[
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17,
+        0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+    ],
+    h: 1,
+};
+
+#[cfg(feature = "ec_params")]
+static P384: EcDomainParameters = EcDomainParameters {
+    p: &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xff,
+    ],
+    a: &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0xff, 0xff, 0xfc,
+    ],
+    b: &[
+        0xb3, 0x31, 0x2f, 0xa7, 0xe2, 0x3e, 0xe7, 0xe4, 0x98, 0x8e, 0x05, 0x6b, 0xe3, 0xf8, 0x2d, 0x19, 0x18, 0x1d, 0x9c, 0x6e, 0xfe, 0x81,
+        0x41, 0x12, 0x03, 0x14, 0x08, 0x8f, 0x50, 0x13, 0x87, 0x5a, 0xc6, 0x56, 0x39, 0x8d, 0x8a, 0x2e, 0xd1, 0x9d, 0x2a, 0x85, 0xc8, 0xed,
+        0xd3, 0xec, 0x2a, 0xef,
+    ],
+    gx: &[
+        0xaa, 0x87, 0xca, 0x22, 0xbe, 0x8b, 0x05, 0x37, 0x8e, 0xb1, 0xc7, 0x1e, 0xf3, 0x20, 0xad, 0x74, 0x6e, 0x1d, 0x3b, 0x62, 0x8b, 0xa7,
+        0x9b, 0x98, 0x59, 0xf7, 0x41, 0xe0, 0x82, 0x54, 0x2a, 0x38, 0x55, 0x02, 0xf2, 0x5d, 0xbf, 0x55, 0x29, 0x6c, 0x3a, 0x54, 0x5e, 0x38,
+        0x72, 0x76, 0x0a, 0xb7,
+    ],
+    gy: &[
+        0x36, 0x17, 0xde, 0x4a, 0x96, 0x26, 0x2c, 0x6f, 0x5d, 0x9e, 0x98, 0xbf, 0x92, 0x92, 0xdc, 0x29, 0xf8, 0xf4, 0x1d, 0xbd, 0x28, 0x9a,
+        0x14, 0x7c, 0xe9, 0xda, 0x31, 0x13, 0xb5, 0xf0, 0xb8, 0xc0, 0x0a, 0x60, 0xb1, 0xce, 0x1d, 0x7e, 0x81, 0x9d, 0x7a, 0x43, 0x1d, 0x7c,
+        0x90, 0xea, 0x0e, 0x5f,
+    ],
+    n: &[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xc7, 0x63, 0x4d, 0x81, 0xf4, 0x37, 0x2d, 0xdf, 0x58, 0x1a, 0x0d, 0xb2, 0x48, 0xb0, 0xa7, 0x7a, 0xec, 0xec, 0x19, 0x6a,
+        0xcc, 0xc5, 0x29, 0x73,
+    ],
+    h: 1,
+};
+
+#[cfg(feature = "ec_params")]
+static P521: EcDomainParameters = EcDomainParameters {
+    p: &[
+        0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ],
+    a: &[
+        0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfc,
+    ],
+    b: &[
+        0x00, 0x51, 0x95, 0x3e, 0xb9, 0x61, 0x8e, 0x1c, 0x9a, 0x1f, 0x92, 0x9a, 0x21, 0xa0, 0xb6, 0x85, 0x40, 0xee, 0xa2, 0xda, 0x72, 0x5b,
+        0x99, 0xb3, 0x15, 0xf3, 0xb8, 0xb4, 0x89, 0x91, 0x8e, 0xf1, 0x09, 0xe1, 0x56, 0x19, 0x39, 0x51, 0xec, 0x7e, 0x93, 0x7b, 0x16, 0x52,
+        0xc0, 0xbd, 0x3b, 0xb1, 0xbf, 0x07, 0x35, 0x73, 0xdf, 0x88, 0x3d, 0x2c, 0x34, 0xf1, 0xef, 0x45, 0x1f, 0xd4, 0x6b, 0x50, 0x3f, 0x00,
+    ],
+    gx: &[
+        0x00, 0xc6, 0x85, 0x8e, 0x06, 0xb7, 0x04, 0x04, 0xe9, 0xcd, 0x9e, 0x3e, 0xcb, 0x66, 0x23, 0x95, 0xb4, 0x42, 0x9c, 0x64, 0x81, 0x39,
+        0x05, 0x3f, 0xb5, 0x21, 0xf8, 0x28, 0xaf, 0x60, 0x6b, 0x4d, 0x3d, 0xba, 0xa1, 0x4b, 0x5e, 0x77, 0xef, 0xe7, 0x59, 0x28, 0xfe, 0x1d,
+        0xc1, 0x27, 0xa2, 0xff, 0xa8, 0xde, 0x33, 0x48, 0xb3, 0xc1, 0x85, 0x6a, 0x42, 0x9b, 0xf9, 0x7e, 0x7e, 0x31, 0xc2, 0xe5, 0xbd, 0x66,
+    ],
+    gy: &[
+        0x01, 0x18, 0x39, 0x29, 0x6a, 0x78, 0x9a, 0x3b, 0xc0, 0x04, 0x5c, 0x8a, 0x5f, 0xb4, 0x2c, 0x7d, 0x1b, 0xd9, 0x98, 0xf5, 0x44, 0x49,
+        0x57, 0x9b, 0x44, 0x68, 0x17, 0xaf, 0xbd, 0x17, 0x27, 0x3e, 0x66, 0x2c, 0x97, 0xee, 0x72, 0x99, 0x5e, 0xf4, 0x26, 0x40, 0xc5, 0x50,
+        0xb9, 0x01, 0x3f, 0xad, 0x07, 0x61, 0x35, 0x3c, 0x70, 0x86, 0xa2, 0x72, 0xc2, 0x40, 0x88, 0xbe, 0x94, 0x76, 0x9f, 0xd1, 0x66, 0x50,
+    ],
+    n: &[
+        0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfa, 0x51, 0x86, 0x87, 0x83, 0xbf, 0x2f, 0x96, 0x6b, 0x7f, 0xcc,
+        0x01, 0x48, 0xf7, 0x09, 0xa5, 0xd0, 0x3b, 0xb5, 0xc9, 0xb8, 0x89, 0x9c, 0x47, 0xae, 0xbb, 0x6f, 0xb7, 0x1e, 0x91, 0x38, 0x64, 0x09,
+    ],
+    h: 1,
+};
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_ec_curve_info() {
+        assert!(ec_curve_info(&oid!(1.2.3.4)).is_none());
+
+        #[cfg(all(feature = "x962", feature = "nist_algs", feature = "x509"))]
+        {
+            use crate::*;
+            use asn1_rs::FromDer;
+
+            let info = ec_curve_info(&OID_NIST_EC_P521).unwrap();
+            assert_eq!((info.name(), info.field_bits(), info.security_bits()), ("secp521r1", 521, 256));
+            assert_eq!(info.aliases(), ["P-521"]);
+            assert_eq!(ec_curve_info(&OID_SIG_ED25519).map(|i| i.form()), Some(CurveForm::Edwards));
+            assert_eq!(ec_curve_info(&OID_KEY_TYPE_X448).map(|i| i.form()), Some(CurveForm::Montgomery));
+            assert_eq!(ec_curve_info(&OID_KEY_TYPE_GOST_R3410_2012_512).map(|i| i.field_bits()), Some(512));
+
+            // id-ecPublicKey parameters
+            let params = Any::from_der(&[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07])
+                .unwrap()
+                .1;
+            assert_eq!(named_curve(&params).map(|i| i.name()), Some("prime256v1"));
+            let null = Any::from_der(&[0x05, 0x00]).unwrap().1;
+            assert!(named_curve(&null).is_none());
+
+            // every curve has properties
+            for (oid, entry) in StaticOidRegistry::new().iter_by_kind(OidKind::EllipticCurve) {
+                assert!(ec_curve_info(oid).is_some(), "no properties for {}", entry.sn());
+            }
+        }
+    }
+
+    #[cfg(feature = "ec_params")]
+    #[test]
+    fn test_ec_domain_parameters() {
+        use crate::{OID_EC_P256, OID_NIST_EC_P384, OID_NIST_EC_P521};
+
+        for (oid, size) in [(OID_EC_P256, 32), (OID_NIST_EC_P384, 48), (OID_NIST_EC_P521, 66)] {
+            let params = ec_domain_parameters(&oid).unwrap();
+            let (gx, gy) = params.generator();
+            for value in [params.p(), params.a(), params.b(), gx, gy, params.order()] {
+                assert_eq!(value.len(), size);
+            }
+            assert_eq!(params.cofactor(), 1);
+        }
+        assert!(ec_domain_parameters(&oid!(1.2.3.4)).is_none());
+    }
+}
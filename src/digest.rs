@@ -0,0 +1,107 @@
+//! Properties of hash algorithms
+
+use crate::static_registry::HASH_ALGORITHMS;
+use asn1_rs::Oid;
+
+/// Family of a hash algorithm
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HashFamily {
+    /// MD2, MD4 and MD5
+    Md,
+    /// SHA-1
+    Sha1,
+    /// SHA-2 (SHA-224, SHA-256, SHA-384, SHA-512)
+    Sha2,
+    /// SHA-3 and SHAKE
+    Sha3,
+    /// GOST R 34.11-94 and GOST R 34.11-2012 (Streebog)
+    Gost,
+    /// RIPEMD
+    Ripemd,
+}
+
+/// Properties of a hash algorithm
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashAlgorithmInfo {
+    pub(crate) family: HashFamily,
+    pub(crate) digest_size: usize,
+    pub(crate) block_size: usize,
+    pub(crate) xof: bool,
+}
+
+impl HashAlgorithmInfo {
+    /// Get the family of the hash algorithm
+    #[inline]
+    pub const fn family(&self) -> HashFamily {
+        self.family
+    }
+
+    /// Get the size of the digest, in bytes
+    ///
+    /// For extendable-output functions, this is the output length used when the algorithm is
+    /// identified by its OID (for ex. 64 bytes for SHAKE256, as specified in RFC 8702).
+    #[inline]
+    pub const fn digest_size(&self) -> usize {
+        self.digest_size
+    }
+
+    /// Get the size of the input block, in bytes
+    #[inline]
+    pub const fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns `true` if the algorithm is an extendable-output function (XOF)
+    #[inline]
+    pub const fn is_xof(&self) -> bool {
+        self.xof
+    }
+}
+
+/// Get the properties of a hash algorithm
+///
+/// Returns `None` if the hash algorithm is unknown, or if its feature is not enabled.
+///
+/// ```rust
+/// use oid_registry::hash_algorithm_info;
+///
+/// # #[cfg(feature = "nist_algs")] {
+/// let info = hash_algorithm_info(&oid_registry::OID_NIST_HASH_SHA384).expect("unknown hash");
+/// assert_eq!(info.digest_size(), 48);
+/// # }
+/// ```
+pub fn hash_algorithm_info(oid: &Oid) -> Option<&'static HashAlgorithmInfo> {
+    HASH_ALGORITHMS.iter().find(|(o, _)| o == oid).map(|(_, info)| info)
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_hash_algorithm_info() {
+        assert!(hash_algorithm_info(&oid!(1.2.3.4)).is_none());
+
+        #[cfg(all(feature = "pkcs1", feature = "nist_algs", feature = "x509"))]
+        {
+            use crate::*;
+
+            let info = hash_algorithm_info(&OID_NIST_HASH_SHA256).unwrap();
+            assert_eq!((info.family(), info.digest_size(), info.block_size()), (HashFamily::Sha2, 32, 64));
+            assert!(!info.is_xof());
+            assert_eq!(hash_algorithm_info(&OID_HASH_SHA1).map(|i| i.digest_size()), Some(20));
+            assert!(hash_algorithm_info(&OID_NIST_HASH_SHAKE256).unwrap().is_xof());
+            assert_eq!(
+                hash_algorithm_info(&OID_HASH_GOST_R3411_2012_512).map(|i| i.family()),
+                Some(HashFamily::Gost)
+            );
+
+            // every hash algorithm has properties
+            for (oid, entry) in StaticOidRegistry::new().iter_by_kind(OidKind::HashAlgorithm) {
+                assert!(hash_algorithm_info(oid).is_some(), "no properties for {}", entry.sn());
+            }
+        }
+    }
+}
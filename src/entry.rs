@@ -0,0 +1,129 @@
+//! Entry API for the registry, similar to `HashMap::entry`
+//!
+//! Unlike `HashMap`, references to the entries are shared references: modifying an entry in
+//! place would bypass the registry indexes. Use `and_modify` to modify an existing entry.
+
+use crate::{Oid, OidEntry, OidKey, OidRegistry};
+
+/// A view into a single entry of the registry, which may either be vacant or occupied
+///
+/// This enum is constructed from the [`entry`](OidRegistry::entry) method on `OidRegistry`.
+#[derive(Debug)]
+pub enum Entry<'r, 'a> {
+    /// An occupied entry
+    Occupied(OccupiedEntry<'r, 'a>),
+    /// A vacant entry
+    Vacant(VacantEntry<'r, 'a>),
+}
+
+/// A view into an occupied entry of the registry
+#[derive(Debug)]
+pub struct OccupiedEntry<'r, 'a> {
+    pub(crate) registry: &'r mut OidRegistry<'a>,
+    pub(crate) oid: Oid<'a>,
+}
+
+/// A view into a vacant entry of the registry
+#[derive(Debug)]
+pub struct VacantEntry<'r, 'a> {
+    pub(crate) registry: &'r mut OidRegistry<'a>,
+    pub(crate) oid: Oid<'a>,
+}
+
+impl<'r, 'a> Entry<'r, 'a> {
+    /// Returns a reference to this entry's OID
+    pub fn key(&self) -> &Oid<'a> {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default if empty, and returns a reference
+    /// to the value in the entry
+    pub fn or_insert<E: Into<OidEntry>>(self, default: E) -> &'r OidEntry {
+        match self {
+            Entry::Occupied(e) => e.into_ref(),
+            Entry::Vacant(e) => e.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns a reference to the value in the entry
+    pub fn or_insert_with<E: Into<OidEntry>, F: FnOnce() -> E>(self, default: F) -> &'r OidEntry {
+        match self {
+            Entry::Occupied(e) => e.into_ref(),
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts into
+    /// the registry
+    pub fn and_modify<F: FnOnce(&mut OidEntry)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut e) => {
+                e.modify(f);
+                Entry::Occupied(e)
+            }
+            Entry::Vacant(e) => Entry::Vacant(e),
+        }
+    }
+}
+
+impl<'r, 'a> OccupiedEntry<'r, 'a> {
+    /// Returns a reference to this entry's OID
+    pub fn key(&self) -> &Oid<'a> {
+        &self.oid
+    }
+
+    /// Gets a reference to the value in the entry
+    pub fn get(&self) -> &OidEntry {
+        self.registry.map.get(self.oid.as_bytes()).expect("occupied entry")
+    }
+
+    /// Converts the entry into a reference to the value, with the lifetime of the registry
+    pub fn into_ref(self) -> &'r OidEntry {
+        let registry: &'r OidRegistry<'a> = self.registry;
+        registry.map.get(self.oid.as_bytes()).expect("occupied entry")
+    }
+
+    /// Modifies the value in the entry, keeping the registry indexes up to date
+    pub fn modify<F: FnOnce(&mut OidEntry)>(&mut self, f: F) {
+        let registry = &mut *self.registry;
+        let mut entry = registry.map.remove(self.oid.as_bytes()).expect("occupied entry");
+        registry.unindex_entry(&self.oid, &entry);
+        f(&mut entry);
+        registry.index_entry(&self.oid, &entry);
+        registry.map.insert(OidKey(self.oid.clone()), entry);
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value
+    pub fn insert<E: Into<OidEntry>>(&mut self, value: E) -> OidEntry {
+        self.registry.insert(self.oid.clone(), value).expect("occupied entry")
+    }
+
+    /// Takes the value out of the registry, and returns it
+    pub fn remove(self) -> OidEntry {
+        self.registry.remove(&self.oid).expect("occupied entry")
+    }
+}
+
+impl<'r, 'a> VacantEntry<'r, 'a> {
+    /// Returns a reference to the OID that would be used when inserting a value
+    pub fn key(&self) -> &Oid<'a> {
+        &self.oid
+    }
+
+    /// Takes ownership of the OID
+    pub fn into_key(self) -> Oid<'a> {
+        self.oid
+    }
+
+    /// Sets the value of the entry, and returns a reference to it
+    pub fn insert<E: Into<OidEntry>>(self, value: E) -> &'r OidEntry {
+        let registry = self.registry;
+        registry.insert(self.oid.clone(), value);
+        let registry: &'r OidRegistry<'a> = registry;
+        registry.map.get(self.oid.as_bytes()).expect("inserted entry")
+    }
+}
@@ -0,0 +1,72 @@
+use crate::ParameterExpectation;
+use alloc::string::String;
+use asn1_rs::{Oid, Tag};
+use core::fmt;
+
+/// An error returned by registry operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OidRegistryError {
+    /// The name matches more than one entry
+    AmbiguousName {
+        /// The name that was looked up
+        name: String,
+        /// The number of matching entries
+        count: usize,
+    },
+    /// The OID is already registered with a different entry
+    Conflict {
+        /// The conflicting OID
+        oid: Oid<'static>,
+        /// The short name of the registered entry
+        existing_sn: String,
+        /// The short name of the inserted entry
+        incoming_sn: String,
+    },
+    /// The global registry is already in use, and cannot be modified
+    GlobalRegistryFrozen,
+    /// No information is known for this algorithm
+    UnknownAlgorithm {
+        /// The algorithm OID
+        oid: Oid<'static>,
+    },
+    /// The parameters of an `AlgorithmIdentifier` do not have the expected form
+    InvalidParameters {
+        /// The algorithm OID
+        oid: Oid<'static>,
+        /// The expected form of the parameters
+        expected: ParameterExpectation,
+        /// The tag of the parameters, or `None` if they were absent
+        found: Option<Tag>,
+    },
+}
+
+impl fmt::Display for OidRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OidRegistryError::AmbiguousName { name, count } => {
+                write!(f, "ambiguous name '{}' ({} matching entries)", name, count)
+            }
+            OidRegistryError::Conflict {
+                oid,
+                existing_sn,
+                incoming_sn,
+            } => write!(
+                f,
+                "conflict for OID {}: registered as '{}', inserted as '{}'",
+                oid, existing_sn, incoming_sn
+            ),
+            OidRegistryError::GlobalRegistryFrozen => f.write_str("global registry is frozen"),
+            OidRegistryError::UnknownAlgorithm { oid } => write!(f, "unknown algorithm {}", oid),
+            OidRegistryError::InvalidParameters { oid, expected, found } => {
+                write!(f, "invalid parameters for algorithm {}: expected {}, found ", oid, expected)?;
+                match found {
+                    Some(tag) => write!(f, "{}", tag),
+                    None => f.write_str("absent"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OidRegistryError {}
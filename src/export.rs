@@ -0,0 +1,149 @@
+//! Writing of registries in the format of the assets files
+
+use crate::load::{set_table_attribute, write_map, LoadedEntry, LoadedMap};
+use crate::{Oid, OidEntry, OidKind, OidRegistry, OidStatus};
+use std::io::{self, Write};
+
+impl OidRegistry<'_> {
+    /// Write the entries of the registry in the format of the assets files
+    ///
+    /// The output is sorted by OID arcs, and can be read back using
+    /// [`load_from_reader`](OidRegistry::load_from_reader) to get the same entries (see
+    /// [`write_map`](crate::write_map) for the format and errors). Registries built from the
+    /// assets (using `with_xxx()` methods or `load_from_reader`) are written back unchanged,
+    /// except for comments and empty lines.
+    ///
+    /// Each entry must have a feature (see [`OidEntry::with_feature`]), otherwise an error of kind
+    /// [`io::ErrorKind::InvalidData`] is returned.
+    ///
+    /// ```rust
+    /// use oid_registry::{OidEntry, OidRegistry};
+    /// use oid_registry::asn1_rs::oid;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.2.3.4), OidEntry::new("shortName", "A description").with_feature("site"));
+    /// let mut output = Vec::new();
+    /// registry.write_tsv(&mut output).unwrap();
+    /// assert_eq!(output, b"site\t\"\"\t1.2.3.4\tshortName\tA description\n");
+    /// ```
+    pub fn write_tsv<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut map = LoadedMap::new();
+        for (oid, entry) in self.iter() {
+            let feature = entry.feature().ok_or_else(|| invalid_data(oid, "no feature".to_string()))?;
+            map.entry(feature.to_string()).or_default().push(loaded_entry(oid, entry)?);
+        }
+        write_map(&map, writer)
+    }
+}
+
+// Convert an entry of the registry to an entry of the assets files
+fn loaded_entry(oid: &Oid, entry: &OidEntry) -> io::Result<LoadedEntry> {
+    let mut loaded = LoadedEntry {
+        name: entry.constant_name().unwrap_or("\"\"").to_string(),
+        oid: oid.to_id_string(),
+        sn: entry.sn().to_string(),
+        description: entry.description().to_string(),
+        aliases: entry.aliases().map(str::to_string).collect(),
+        long_name: entry.long_name().map(str::to_string),
+        references: entry.references().map(str::to_string).collect(),
+        kind: match entry.kind() {
+            OidKind::Other => None,
+            kind => Some(kind.as_str().to_string()),
+        },
+        status: match entry.status() {
+            OidStatus::Current => None,
+            status => Some(status.as_str().to_string()),
+        },
+        ..Default::default()
+    };
+    for (key, value) in entry.attributes() {
+        set_table_attribute(&mut loaded, 0, key, value).map_err(|_| invalid_data(oid, format!("invalid attribute {}={}", key, value)))?;
+    }
+    Ok(loaded)
+}
+
+fn invalid_data(oid: &Oid, message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("cannot write entry {}: {}", oid, message))
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_write_tsv() {
+        let mut registry = OidRegistry::default();
+        registry.insert(
+            oid!(1.2.10),
+            OidEntry::new("ten", "Sorted by arcs, not by text").with_feature("my_site"),
+        );
+        registry.insert(
+            oid!(1.2.9),
+            OidEntry::new("nine", "")
+                .with_alias("neuf")
+                .with_reference("RFC 9")
+                .with_status(OidStatus::Obsolete)
+                .with_feature("my_site")
+                .with_constant_name("OID_NINE"),
+        );
+        registry.insert(
+            oid!(1.2.9.1),
+            OidEntry::new("unicode", "Caf\u{e9} \"quoted\" \\")
+                .with_long_name("Long name")
+                .with_kind(OidKind::Arc)
+                .with_attribute("strength", "128")
+                .with_feature("other"),
+        );
+
+        let mut output = Vec::new();
+        registry.write_tsv(&mut output).unwrap();
+        let expected = "my-site\tOID_NINE\t1.2.9\tnine\t\tstatus=obsolete\talias=neuf\tref=RFC 9\n\
+                        other\t\"\"\t1.2.9.1\tunicode\tCaf\u{e9} \"quoted\" \\\tkind=arc\tstrength=128\tln=Long name\n\
+                        my-site\t\"\"\t1.2.10\tten\tSorted by arcs, not by text\n";
+        assert_eq!(String::from_utf8(output.clone()).unwrap(), expected);
+
+        // read back
+        let mut loaded = OidRegistry::default();
+        assert_eq!(loaded.load_from_reader(&output[..], None).unwrap(), 3);
+        for (oid, entry) in registry.iter() {
+            assert_eq!(loaded.get(oid), Some(entry));
+        }
+
+        // entries with tabs or line breaks, or without feature, cannot be written
+        registry.insert(oid!(1.2.11), OidEntry::new("eleven", "Line\nbreak").with_feature("my_site"));
+        let e = registry.write_tsv(io::sink()).expect_err("invalid entry");
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        registry.insert(oid!(1.2.11), ("eleven", "No feature"));
+        let e = registry.write_tsv(io::sink()).expect_err("no feature");
+        assert_eq!(e.to_string(), "cannot write entry 1.2.11: no feature");
+    }
+
+    #[test]
+    fn test_write_tsv_assets() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/oid_db.txt");
+        let mut registry = OidRegistry::default();
+        registry.load_from_path(path, None).unwrap();
+        let mut output = Vec::new();
+        registry.write_tsv(&mut output).unwrap();
+
+        // the output is the assets file, without comments and empty lines
+        let assets = std::fs::read_to_string(path).unwrap();
+        let assets: String = assets
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        assert_eq!(String::from_utf8(output).unwrap(), assets);
+
+        // the generated registry is written the same way
+        // (`crypto` enables all other features)
+        #[cfg(all(feature = "registry", feature = "crypto", feature = "ms_spc", feature = "x500", feature = "x509"))]
+        {
+            let mut output = Vec::new();
+            OidRegistry::default().with_all_features().write_tsv(&mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), assets);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Process-wide registry
+
+use crate::{Oid, OidEntry, OidRegistry, OidRegistryError};
+use once_cell::sync::OnceCell;
+use std::sync::{Mutex, PoisonError};
+
+// The frozen registry. Once initialized, it is read without locking.
+static GLOBAL: OnceCell<OidRegistry<'static>> = OnceCell::new();
+
+// Entries registered before the first use of the global registry, `None` once frozen
+static PENDING: Mutex<Option<Vec<(Oid<'static>, OidEntry)>>> = Mutex::new(Some(Vec::new()));
+
+/// Get the process-wide registry
+///
+/// On first use, the registry is populated with the OIDs of all enabled features (see
+/// [`OidRegistry::with_all_features`]), and with the entries added using [`register_global`].
+/// The registry is then frozen, and cannot be modified. After initialization, this function
+/// does not take any lock, so it can be used in `Display` implementations or for logging.
+///
+/// ```rust
+/// use asn1_rs::oid;
+///
+/// let registry = oid_registry::global();
+/// if let Some(entry) = registry.get(&oid!(2.5.4.3)) {
+///     println!("sn: {}", entry.sn());
+/// }
+/// ```
+pub fn global() -> &'static OidRegistry<'static> {
+    GLOBAL.get_or_init(|| {
+        let pending = PENDING.lock().unwrap_or_else(PoisonError::into_inner).take();
+        let mut registry = OidRegistry::default().with_all_features();
+        registry.extend(pending.unwrap_or_default());
+        registry
+    })
+}
+
+/// Add an entry to the process-wide registry
+///
+/// Entries must be registered before the first call to [`global`], usually at the start of the
+/// application. They take precedence over the known OIDs of enabled features.
+///
+/// Returns an error if the global registry is already frozen.
+///
+/// This function is thread-safe.
+///
+/// ```rust
+/// use asn1_rs::oid;
+/// use oid_registry::register_global;
+///
+/// register_global(oid!(1.2.3.4), ("shortName", "A description")).expect("registry is frozen");
+/// ```
+pub fn register_global<E>(oid: Oid<'static>, entry: E) -> Result<(), OidRegistryError>
+where
+    E: Into<OidEntry>,
+{
+    let mut pending = PENDING.lock().unwrap_or_else(PoisonError::into_inner);
+    match &mut *pending {
+        None => Err(OidRegistryError::GlobalRegistryFrozen),
+        Some(pending) => {
+            pending.push((oid, entry.into()));
+            Ok(())
+        }
+    }
+}
+
+#[rustfmt::skip::macros(oid)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_global_registry() {
+        register_global(oid!(1.2.3.4), ("shortName", "A description")).expect("registry is frozen");
+        let registry = global();
+        assert_eq!(registry.get(&oid!(1.2.3.4)).map(|e| e.sn()), Some("shortName"));
+        #[cfg(feature = "x509")]
+        assert!(registry.get(&crate::OID_X509_COMMON_NAME).is_some());
+        assert!(std::ptr::eq(registry, global()));
+        assert_eq!(
+            register_global(oid!(1.2.3.5), ("otherName", "")),
+            Err(OidRegistryError::GlobalRegistryFrozen)
+        );
+    }
+}
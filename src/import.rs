@@ -0,0 +1,157 @@
+//! Loading of OID declarations in a registry at runtime
+
+use crate::load::{load_entries, open_file, table_attributes, LineError, LoadError, LoadedEntry};
+use crate::{Oid, OidEntry, OidKind, OidRegistry, OidStatus};
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+
+impl OidRegistry<'static> {
+    /// Load OID declarations from a reader, and insert them in the registry
+    ///
+    /// The data must use the format of the assets files (see [`load_file`](crate::load_file)).
+    /// If `features` is set, only the entries of these features are loaded (`-` and `_` are
+    /// equivalent in feature names).
+    ///
+    /// The feature, the constant name and the attributes only used to generate static tables (for
+    /// ex. `hash` or `strength`) are kept in the entries, see [`OidEntry::attributes`].
+    /// Loaded entries replace the existing entries with the same OID.
+    ///
+    /// Return the number of loaded entries. If an error occurs, the registry is not modified.
+    ///
+    /// ```rust
+    /// use oid_registry::{OidKind, OidRegistry};
+    /// use oid_registry::asn1_rs::oid;
+    ///
+    /// let data = "site\tOID_SITE_POLICY\t1.3.6.1.4.1.99999.1\tsitePolicy\tSite certificate policy\tkind=other\n";
+    /// let mut registry = OidRegistry::default();
+    /// assert_eq!(registry.load_from_reader(data.as_bytes(), None).unwrap(), 1);
+    /// let entry = registry.get(&oid!(1.3.6.1.4.1.99999.1)).expect("not found");
+    /// assert_eq!(entry.sn(), "sitePolicy");
+    /// ```
+    pub fn load_from_reader<R: BufRead>(&mut self, reader: R, features: Option<&[&str]>) -> Result<usize, LoadError> {
+        self.load(reader, None, features)
+    }
+
+    /// Load OID declarations from a file, and insert them in the registry
+    ///
+    /// See [`load_from_reader`](OidRegistry::load_from_reader).
+    pub fn load_from_path<P: AsRef<Path>>(&mut self, path: P, features: Option<&[&str]>) -> Result<usize, LoadError> {
+        let path = path.as_ref();
+        self.load(open_file(path)?, Some(path), features)
+    }
+
+    fn load<R: BufRead>(&mut self, reader: R, path: Option<&Path>, features: Option<&[&str]>) -> Result<usize, LoadError> {
+        let mut entries = Vec::new();
+        load_entries(reader, path, None, |feature, entry| {
+            let selected = features.map_or(true, |features| features.iter().any(|f| f.replace('-', "_") == feature));
+            if selected {
+                entries.push(convert_entry(feature, entry)?);
+            }
+            Ok(())
+        })?;
+        let count = entries.len();
+        self.extend(entries);
+        Ok(count)
+    }
+}
+
+fn convert_entry(feature: String, entry: LoadedEntry) -> Result<(Oid<'static>, OidEntry), LineError> {
+    // the OID is the third column
+    let oid = Oid::from_str(&entry.oid).map_err(|_| LineError::Invalid(3, "oid".to_string(), entry.oid.clone()))?;
+    let mut oid_entry = OidEntry::new(entry.sn.clone(), entry.description.clone()).with_feature(feature);
+    if entry.name != "\"\"" {
+        oid_entry = oid_entry.with_constant_name(entry.name.clone());
+    }
+    for (key, value) in table_attributes(&entry) {
+        oid_entry = oid_entry.with_attribute(key, value);
+    }
+    for alias in entry.aliases {
+        oid_entry = oid_entry.with_alias(alias);
+    }
+    if let Some(long_name) = entry.long_name {
+        oid_entry = oid_entry.with_long_name(long_name);
+    }
+    for reference in entry.references {
+        oid_entry = oid_entry.with_reference(reference);
+    }
+    // kind and status were checked by the loader
+    let kind = entry.kind.as_deref().and_then(OidKind::from_name).unwrap_or_default();
+    let status = entry.status.as_deref().and_then(OidStatus::from_name).unwrap_or_default();
+    Ok((oid, oid_entry.with_kind(kind).with_status(status)))
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    const DATA: &str = "# site OIDs\n\
+                        site\tOID_A\t1.2.3.1\ta\tEntry A\talias=entryA\tln=Entry-A\tref=RFC 1\tref=RFC 2\tkind=attribute\tstatus=deprecated\n\
+                        site\tOID_B\t1.2.3.2\tb\tEntry B\n\
+                        other-site\tOID_C\t1.2.3.3\tc\tEntry C\tstrength=128\n";
+
+    #[test]
+    fn test_load_from_reader() {
+        let mut registry = OidRegistry::default();
+        assert_eq!(registry.load_from_reader(DATA.as_bytes(), None).unwrap(), 3);
+        let entry = registry.get(&oid!(1.2.3.1)).expect("not found");
+        assert_eq!(entry.sn(), "a");
+        assert_eq!(entry.description(), "Entry A");
+        assert!(entry.aliases().eq(["entryA"]));
+        assert_eq!(entry.long_name(), Some("Entry-A"));
+        assert!(entry.references().eq(["RFC 1", "RFC 2"]));
+        assert_eq!(entry.kind(), OidKind::Attribute);
+        assert_eq!(entry.status(), OidStatus::Deprecated);
+        let entry = registry.get(&oid!(1.2.3.3)).expect("not found");
+        assert_eq!(entry.kind(), OidKind::Other);
+        assert_eq!(entry.feature(), Some("other_site"));
+        assert_eq!(entry.constant_name(), Some("OID_C"));
+        assert!(entry.attributes().eq([("strength", "128")]));
+
+        // filter by feature
+        let mut registry = OidRegistry::default();
+        assert_eq!(registry.load_from_reader(DATA.as_bytes(), Some(&["other-site"])).unwrap(), 1);
+        assert!(registry.contains(&oid!(1.2.3.3)));
+        assert!(!registry.contains(&oid!(1.2.3.1)));
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.1), ("existing", "Existing entry"));
+
+        // the OID is valid, but cannot be encoded
+        let data = format!("{}site\tOID_D\t2.999.1\td\tEntry D\n", DATA);
+        let e = registry.load_from_reader(data.as_bytes(), None).expect_err("loading should fail");
+        assert!(matches!(&e, LoadError::InvalidField { line: 5, column: 3, field, .. } if field == "oid"));
+        assert_eq!(e.to_string(), "line 5, column 3: invalid value for 'oid': '2.999.1'");
+
+        // the registry is not modified on errors
+        let data = format!("{}site\tOID_D\t1.2.3.4\n", DATA);
+        assert!(registry.load_from_reader(data.as_bytes(), None).is_err());
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get(&oid!(1.2.3.1)).map(OidEntry::sn), Some("existing"));
+
+        let e = registry.load_from_path("/nonexistent/oids.txt", None).expect_err("missing file");
+        assert_eq!(e.path(), Some(Path::new("/nonexistent/oids.txt")));
+    }
+
+    #[test]
+    fn test_load_assets() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/oid_db.txt");
+        let mut registry = OidRegistry::default();
+        let count = registry.load_from_path(path, Some(&["x509"])).unwrap();
+        assert_eq!(count, registry.len());
+        assert!(registry.contains(&oid!(2.5.29.19)));
+        #[cfg(all(feature = "registry", feature = "x509"))]
+        {
+            let generated = OidRegistry::default().with_x509();
+            assert_eq!(registry.len(), generated.len());
+            for (oid, entry) in generated.iter() {
+                assert_eq!(registry.get(oid), Some(entry));
+            }
+        }
+    }
+}
@@ -58,6 +58,12 @@
 //!
 //! ```
 //!
+//! ## `no_std` support
+//!
+//! This crate supports `no_std` environments with `alloc`, by disabling the `std` default
+//! feature. In that case, the registry is backed by a `BTreeMap`, and the functions loading files
+//! (including `OidRegistry::load_from_path`) and the global registry are not available.
+//!
 //! ## Versions and compatibility with `asn1-rs`
 //!
 //! Versions of `oid-registry` must be chosen specifically, to depend on a precise version of `asn1-rs`.
@@ -83,24 +89,91 @@
 // pragmas for doc
 // #![deny(intra_doc_link_resolution_failure)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub use asn1_rs;
 pub use asn1_rs::Oid;
 
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use asn1_rs::oid;
-use std::borrow::Cow;
-use std::collections::HashMap;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
 
+// The registry is backed by a `HashMap` if `std` is available, and a `BTreeMap` otherwise
+#[cfg(feature = "std")]
+type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
+mod conflict;
+mod curve;
+mod digest;
+mod entry;
+mod error;
+#[cfg(feature = "std")]
+mod export;
+#[cfg(all(feature = "registry", feature = "std"))]
+mod global;
+#[cfg(feature = "std")]
+mod import;
+#[cfg(feature = "std")]
 mod load;
+#[cfg(test)]
+mod manifest;
+mod metadata;
+mod overlay;
+mod params;
+mod policy;
+mod resolve;
+mod search;
+mod signature;
+mod static_registry;
+mod tree;
 
+pub use conflict::*;
+pub use curve::*;
+pub use digest::*;
+pub use entry::*;
+pub use error::*;
+#[cfg(all(feature = "registry", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "registry", feature = "std"))))]
+pub use global::*;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use load::*;
+pub use metadata::*;
+pub use overlay::*;
+pub use params::*;
+pub use policy::*;
+pub use resolve::*;
+pub use signature::*;
+pub use static_registry::*;
+use tree::ArcNode;
 
 /// An entry stored in the OID registry
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OidEntry {
     // Short name
     sn: Cow<'static, str>,
     description: Cow<'static, str>,
+    // Alternative short names
+    aliases: Cow<'static, [Cow<'static, str>]>,
+    long_name: Option<Cow<'static, str>>,
+    // Specification references (RFC numbers, standard clauses, URLs)
+    references: Cow<'static, [Cow<'static, str>]>,
+    kind: OidKind,
+    status: OidStatus,
+    // Columns of the assets files, only used to write the entry back
+    feature: Option<Cow<'static, str>>,
+    constant_name: Option<Cow<'static, str>>,
+    attributes: Cow<'static, [(Cow<'static, str>, Cow<'static, str>)]>,
 }
 
 impl OidEntry {
@@ -112,7 +185,119 @@ impl OidEntry {
     {
         let sn = sn.into();
         let description = description.into();
-        OidEntry { sn, description }
+        OidEntry {
+            sn,
+            description,
+            aliases: Cow::Borrowed(&[]),
+            long_name: None,
+            references: Cow::Borrowed(&[]),
+            kind: OidKind::Other,
+            status: OidStatus::Current,
+            feature: None,
+            constant_name: None,
+            attributes: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Create a new entry from static strings
+    ///
+    /// This function can be used in constant expressions.
+    pub const fn new_static(sn: &'static str, description: &'static str) -> OidEntry {
+        Self::from_static_parts(sn, description, &[], None, &[], OidKind::Other, OidStatus::Current, None, None, &[])
+    }
+
+    // Create a new entry with all metadata from static values (used by the generated tables)
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) const fn from_static_parts(
+        sn: &'static str,
+        description: &'static str,
+        aliases: &'static [Cow<'static, str>],
+        long_name: Option<&'static str>,
+        references: &'static [Cow<'static, str>],
+        kind: OidKind,
+        status: OidStatus,
+        feature: Option<&'static str>,
+        constant_name: Option<&'static str>,
+        attributes: &'static [(Cow<'static, str>, Cow<'static, str>)],
+    ) -> OidEntry {
+        OidEntry {
+            sn: Cow::Borrowed(sn),
+            description: Cow::Borrowed(description),
+            aliases: Cow::Borrowed(aliases),
+            long_name: static_cow(long_name),
+            references: Cow::Borrowed(references),
+            kind,
+            status,
+            feature: static_cow(feature),
+            constant_name: static_cow(constant_name),
+            attributes: Cow::Borrowed(attributes),
+        }
+    }
+
+    /// Add an alternative short name to this entry
+    ///
+    /// Aliases are indexed by the registry, and can be used in lookups by short name.
+    pub fn with_alias<S: Into<Cow<'static, str>>>(mut self, alias: S) -> Self {
+        self.add_alias(alias);
+        self
+    }
+
+    /// Set the long name of this entry (for ex. `organizationalUnitName`)
+    pub fn with_long_name<S: Into<Cow<'static, str>>>(mut self, long_name: S) -> Self {
+        self.long_name = Some(long_name.into());
+        self
+    }
+
+    /// Add a specification reference to this entry (for ex. `RFC 5280`, or a URL)
+    pub fn with_reference<S: Into<Cow<'static, str>>>(mut self, reference: S) -> Self {
+        self.references.to_mut().push(reference.into());
+        self
+    }
+
+    /// Set the kind of this entry
+    pub fn with_kind(mut self, kind: OidKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set the status of this entry
+    pub fn with_status(mut self, status: OidStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the feature declaring this entry in the assets files (for ex. `x509`)
+    pub fn with_feature<S: Into<Cow<'static, str>>>(mut self, feature: S) -> Self {
+        self.feature = Some(feature.into());
+        self
+    }
+
+    /// Set the name of the constant declared for this entry (for ex. `OID_X509_COMMON_NAME`)
+    pub fn with_constant_name<S: Into<Cow<'static, str>>>(mut self, name: S) -> Self {
+        self.constant_name = Some(name.into());
+        self
+    }
+
+    /// Add an attribute of the assets files which is not stored in other fields of the entry (for
+    /// ex. `strength` or `hash`)
+    ///
+    /// These attributes are only kept to write the entry back, see
+    /// [`write_tsv`](OidRegistry::write_tsv).
+    pub fn with_attribute<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        self.attributes.to_mut().push((key.into(), value.into()));
+        self
+    }
+
+    // Add an alias, if not already a name of this entry
+    pub(crate) fn add_alias<S: Into<Cow<'static, str>>>(&mut self, alias: S) {
+        let alias = alias.into();
+        if self.names().all(|name| *name != alias) {
+            self.aliases.to_mut().push(alias);
+        }
     }
 
     /// Get the short name for this entry
@@ -126,6 +311,65 @@ impl OidEntry {
     pub fn description(&self) -> &str {
         &self.description
     }
+
+    /// Get the alternative short names for this entry
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(|s| s.as_ref())
+    }
+
+    /// Get the long name for this entry, if any
+    #[inline]
+    pub fn long_name(&self) -> Option<&str> {
+        self.long_name.as_deref()
+    }
+
+    /// Get the specification references for this entry
+    pub fn references(&self) -> impl Iterator<Item = &str> {
+        self.references.iter().map(|s| s.as_ref())
+    }
+
+    /// Get the kind of this entry
+    ///
+    /// Entries created without a kind are classified as [`OidKind::Other`].
+    #[inline]
+    pub fn kind(&self) -> OidKind {
+        self.kind
+    }
+
+    /// Get the status of this entry
+    #[inline]
+    pub fn status(&self) -> OidStatus {
+        self.status
+    }
+
+    /// Get the feature declaring this entry in the assets files, if any
+    #[inline]
+    pub fn feature(&self) -> Option<&str> {
+        self.feature.as_deref()
+    }
+
+    /// Get the name of the constant declared for this entry, if any
+    #[inline]
+    pub fn constant_name(&self) -> Option<&str> {
+        self.constant_name.as_deref()
+    }
+
+    /// Get the other attributes of the assets files for this entry, as `(key, value)` pairs
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+
+    // Short name and aliases
+    pub(crate) fn names(&self) -> impl Iterator<Item = &Cow<'static, str>> {
+        core::iter::once(&self.sn).chain(self.aliases.iter())
+    }
+}
+
+const fn static_cow(s: Option<&'static str>) -> Option<Cow<'static, str>> {
+    match s {
+        Some(s) => Some(Cow::Borrowed(s)),
+        None => None,
+    }
 }
 
 impl From<(&'static str, &'static str)> for OidEntry {
@@ -134,6 +378,45 @@ impl From<(&'static str, &'static str)> for OidEntry {
     }
 }
 
+// Key of the registry map
+//
+// OIDs are compared using their encoded bytes, and the key can be borrowed as `[u8]`. This allows
+// lookups without building or cloning an `Oid`.
+#[derive(Debug, Clone)]
+struct OidKey<'a>(Oid<'a>);
+
+impl PartialEq for OidKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_bytes() == other.0.as_bytes()
+    }
+}
+
+impl Eq for OidKey<'_> {}
+
+impl PartialOrd for OidKey<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OidKey<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_bytes().cmp(other.0.as_bytes())
+    }
+}
+
+impl Hash for OidKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_bytes().hash(state)
+    }
+}
+
+impl Borrow<[u8]> for OidKey<'_> {
+    fn borrow(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
 /// Registry of known OIDs
 ///
 /// Use `OidRegistry::default()` to create an empty registry. If the corresponding features have
@@ -171,7 +454,10 @@ impl From<(&'static str, &'static str)> for OidEntry {
 /// ```
 #[derive(Debug, Default)]
 pub struct OidRegistry<'a> {
-    map: HashMap<Oid<'a>, OidEntry>,
+    map: Map<OidKey<'a>, OidEntry>,
+    tree: ArcNode<'a>,
+    // Secondary index: short name to OIDs
+    sn_index: Map<Cow<'static, str>, Vec<Oid<'a>>>,
 }
 
 impl<'a> OidRegistry<'a> {
@@ -180,17 +466,211 @@ impl<'a> OidRegistry<'a> {
     where
         E: Into<OidEntry>,
     {
-        self.map.insert(oid, entry.into())
+        let entry = entry.into();
+        let previous = self.map.remove(oid.as_bytes());
+        if let Some(prev) = &previous {
+            self.unindex_entry(&oid, prev);
+        }
+        self.index_entry(&oid, &entry);
+        if let Some(arcs) = oid.iter() {
+            self.tree.insert(arcs, oid.clone());
+        }
+        self.map.insert(OidKey(oid), entry);
+        previous
+    }
+
+    /// Convert the registry to a registry with owned OIDs
+    ///
+    /// OIDs borrowing data are copied, so the returned registry does not depend on the lifetime of
+    /// the original OIDs, and can be stored in long-lived state.
+    pub fn into_owned(self) -> OidRegistry<'static> {
+        let mut registry = OidRegistry::default();
+        for (key, entry) in self.map {
+            registry.insert(key.0.to_owned(), entry);
+        }
+        registry
+    }
+
+    /// Removes an entry from the registry, returning it if the OID was registered
+    pub fn remove(&mut self, oid: &Oid) -> Option<OidEntry> {
+        let entry = self.map.remove(oid.as_bytes())?;
+        self.unindex_entry(oid, &entry);
+        if let Some(arcs) = oid.iter() {
+            self.tree.remove(arcs);
+        }
+        Some(entry)
+    }
+
+    /// Retains only the entries specified by the predicate
+    ///
+    /// All entries for which `f(&oid, &entry)` returns `false` are removed.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.2.840.113549.1.1.4), ("md5WithRSAEncryption", "MD5 with RSA encryption"));
+    /// registry.insert(oid!(1.2.840.113549.1.1.11), ("sha256WithRSAEncryption", "SHA256 with RSA encryption"));
+    ///
+    /// // remove weak algorithms
+    /// registry.retain(|_oid, entry| !entry.sn().starts_with("md5"));
+    /// assert_eq!(registry.len(), 1);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Oid<'a>, &OidEntry) -> bool,
+    {
+        let removed: Vec<_> = self
+            .map
+            .iter()
+            .filter(|(key, entry)| !f(&key.0, entry))
+            .map(|(key, _)| key.0.clone())
+            .collect();
+        for oid in &removed {
+            self.remove(oid);
+        }
+    }
+
+    /// Removes all entries from the registry
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.tree = ArcNode::default();
+        self.sn_index.clear();
+    }
+
+    /// Returns the number of entries in the registry
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the registry contains no entry
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if the registry contains an entry for this OID
+    #[inline]
+    pub fn contains(&self, oid: &Oid) -> bool {
+        self.map.contains_key(oid.as_bytes())
+    }
+
+    /// Gets the given OID's corresponding entry in the registry for in-place manipulation
+    ///
+    /// This is similar to `HashMap::entry`.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::{OidEntry, OidRegistry};
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// let entry = registry.entry(oid!(1.2.3.4)).or_insert(("shortName", "A description"));
+    /// assert_eq!(entry.sn(), "shortName");
+    ///
+    /// registry
+    ///     .entry(oid!(1.2.3.4))
+    ///     .and_modify(|e| *e = OidEntry::new("newName", "Another description"))
+    ///     .or_insert(("shortName", "A description"));
+    /// assert!(registry.get_by_sn("newName").unwrap().is_some());
+    /// ```
+    pub fn entry(&mut self, oid: Oid<'a>) -> Entry<'_, 'a> {
+        if self.map.contains_key(oid.as_bytes()) {
+            Entry::Occupied(OccupiedEntry { registry: self, oid })
+        } else {
+            Entry::Vacant(VacantEntry { registry: self, oid })
+        }
+    }
+
+    // Add `oid` to the secondary index, for all names of `entry`
+    fn index_entry(&mut self, oid: &Oid<'a>, entry: &OidEntry) {
+        for name in entry.names() {
+            let v = self.sn_index.entry(name.clone()).or_default();
+            if !v.contains(oid) {
+                v.push(oid.clone());
+            }
+        }
+    }
+
+    // Remove `oid` from the secondary index, for all names of `entry`
+    fn unindex_entry(&mut self, oid: &Oid, entry: &OidEntry) {
+        for name in entry.names() {
+            if let Some(v) = self.sn_index.get_mut(name.as_ref()) {
+                v.retain(|o| o != oid);
+                if v.is_empty() {
+                    self.sn_index.remove(name.as_ref());
+                }
+            }
+        }
+    }
+
+    // Return the key/value pair for a registered OID
+    fn get_pair(&self, oid: &Oid) -> Option<(&Oid<'a>, &OidEntry)> {
+        self.map.get_key_value(oid.as_bytes()).map(|(k, e)| (&k.0, e))
     }
 
     /// Returns a reference to the registry entry, if found for this OID.
-    pub fn get(&self, oid: &Oid<'a>) -> Option<&OidEntry> {
-        self.map.get(oid)
+    ///
+    /// The lifetime of `oid` is not related to the lifetime of the registry, so OIDs borrowed from
+    /// short-lived buffers can be used to query a long-lived registry without copying them.
+    ///
+    /// ```rust
+    /// use asn1_rs::Oid;
+    /// use oid_registry::OidRegistry;
+    /// use std::borrow::Cow;
+    ///
+    /// fn get_sn(registry: &OidRegistry<'static>, data: &[u8]) -> Option<String> {
+    ///     let oid = Oid::new(Cow::Borrowed(data));
+    ///     registry.get(&oid).map(|entry| entry.sn().to_string())
+    /// }
+    /// ```
+    pub fn get(&self, oid: &Oid) -> Option<&OidEntry> {
+        self.map.get(oid.as_bytes())
+    }
+
+    /// Returns a reference to the registry entry, if found for the OID with this DER encoding.
+    ///
+    /// `der` contains the content octets of the OID (without the tag and length), for ex. as
+    /// returned by `Oid::as_bytes`. No `Oid` object is built, so this function is suited to
+    /// lookups of OIDs in raw BER/DER data.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.5.4.3), ("commonName", "Common Name"));
+    ///
+    /// let entry = registry.get_by_der(&[0x55, 0x04, 0x03]).expect("not found");
+    /// assert_eq!(entry.sn(), "commonName");
+    /// ```
+    pub fn get_by_der(&self, der: &[u8]) -> Option<&OidEntry> {
+        self.map.get(der)
+    }
+
+    /// Returns a reference to the registry entry, if found for the OID with these arcs.
+    ///
+    /// No `Oid` object is built: the arcs are looked up in the OID hierarchy.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.5.4.3), ("commonName", "Common Name"));
+    ///
+    /// let entry = registry.get_by_arcs(&[2, 5, 4, 3]).expect("not found");
+    /// assert_eq!(entry.sn(), "commonName");
+    /// ```
+    pub fn get_by_arcs(&self, arcs: &[u64]) -> Option<&OidEntry> {
+        let oid = self.tree.find(arcs.iter().copied())?.oid()?;
+        self.map.get(oid.as_bytes())
     }
 
     /// Return an Iterator over references to the OID numbers (registry keys)
     pub fn keys(&self) -> impl Iterator<Item = &Oid<'a>> {
-        self.map.keys()
+        self.map.keys().map(|k| &k.0)
     }
 
     /// Return an Iterator over references to the `OidEntry` values
@@ -200,10 +680,10 @@ impl<'a> OidRegistry<'a> {
 
     /// Return an Iterator over references to the `(Oid, OidEntry)` key/value pairs
     pub fn iter(&self) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
-        self.map.iter()
+        self.map.iter().map(|(k, e)| (&k.0, e))
     }
 
-    /// Return the `(Oid, OidEntry)` key/value pairs, matching a short name
+    /// Return the `(Oid, OidEntry)` key/value pairs, matching a short name or an alias
     ///
     /// The registry should not contain entries with same short name to avoid ambiguity, but it is
     /// not mandatory.
@@ -211,6 +691,8 @@ impl<'a> OidRegistry<'a> {
     /// This function returns an iterator over the key/value pairs. In most cases, it will have 0
     /// (not found) or 1 item, but can contain more if there are multiple definitions.
     ///
+    /// Short names are indexed, so this function does not allocate nor scan the registry.
+    ///
     /// ```rust
     /// # use oid_registry::OidRegistry;
     /// #
@@ -226,9 +708,164 @@ impl<'a> OidRegistry<'a> {
     ///     // do something
     /// }
     /// ```
-    pub fn iter_by_sn<S: Into<String>>(&self, sn: S) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
-        let s = sn.into();
-        self.map.iter().filter(move |(_, entry)| entry.sn == s)
+    pub fn iter_by_sn<S: AsRef<str>>(&self, sn: S) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        self.iter_by_sn_str(sn.as_ref())
+    }
+
+    // Non-generic version of `iter_by_sn`, so the iterator does not borrow `sn`
+    fn iter_by_sn_str(&self, sn: &str) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        let oids = match self.sn_index.get(sn) {
+            Some(v) => v.as_slice(),
+            None => &[],
+        };
+        oids.iter().filter_map(move |oid| self.get_pair(oid))
+    }
+
+    /// Return an Iterator over the `(Oid, OidEntry)` key/value pairs of a given kind
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::{OidEntry, OidKind, OidRegistry};
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// let entry = OidEntry::new("prime256v1", "P-256 elliptic curve").with_kind(OidKind::EllipticCurve);
+    /// registry.insert(oid!(1.2.840.10045.3.1.7), entry);
+    ///
+    /// for (oid, entry) in registry.iter_by_kind(OidKind::EllipticCurve) {
+    ///     println!("curve: {} ({})", entry.sn(), oid);
+    /// }
+    /// ```
+    pub fn iter_by_kind(&self, kind: OidKind) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        self.iter().filter(move |(_, entry)| entry.kind == kind)
+    }
+
+    /// Return the `(Oid, OidEntry)` key/value pair matching a short name, if unique
+    ///
+    /// Returns `Ok(None)` if no entry matches, or an `AmbiguousName` error if more than one entry
+    /// has this short name.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.16.840.1.101.3.4.2.1), ("sha256", "SHA256"));
+    ///
+    /// let (oid, _entry) = registry.get_by_sn("sha256").unwrap().expect("not found");
+    /// assert_eq!(oid, &oid!(2.16.840.1.101.3.4.2.1));
+    /// ```
+    pub fn get_by_sn(&self, sn: &str) -> Result<Option<(&Oid<'a>, &OidEntry)>, OidRegistryError> {
+        let oids = match self.sn_index.get(sn) {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        match oids.as_slice() {
+            [oid] => Ok(self.get_pair(oid)),
+            _ => Err(OidRegistryError::AmbiguousName {
+                name: sn.to_string(),
+                count: oids.len(),
+            }),
+        }
+    }
+
+    /// Returns the deepest registered OID which is a prefix of the OID with these arcs (or this
+    /// OID itself), with its entry and the remaining arcs
+    ///
+    /// This is useful to describe unknown OIDs relatively to a known one. If the OID is
+    /// registered, the remaining arcs are empty.
+    ///
+    /// Like [`get_by_arcs`](OidRegistry::get_by_arcs), the OID is given by its arcs (see
+    /// [`Oid::iter`]), so the remaining arcs are returned as a slice of `arcs`, without
+    /// allocating.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.3.6.1.4.1.311.60.2.1.1), ("msJurisdictionLocality", "X520LocalityName"));
+    ///
+    /// let (oid, entry, remaining) = registry.get_closest(&[1, 3, 6, 1, 4, 1, 311, 60, 2, 1, 1, 9]).expect("not found");
+    /// assert_eq!(oid, &oid!(1.3.6.1.4.1.311.60.2.1.1));
+    /// assert_eq!(entry.sn(), "msJurisdictionLocality");
+    /// assert_eq!(remaining, [9]);
+    /// ```
+    pub fn get_closest<'r>(&self, arcs: &'r [u64]) -> Option<(&Oid<'a>, &OidEntry, &'r [u64])> {
+        let (closest, depth) = self.tree.closest(arcs.iter().copied())?;
+        let (closest, entry) = self.get_pair(closest)?;
+        Some((closest, entry, &arcs[depth..]))
+    }
+
+    /// Return the closest registered ancestor of `oid`, if any
+    ///
+    /// The parent is the longest registered OID which is a strict prefix of `oid`. `oid` itself
+    /// does not need to be registered.
+    ///
+    /// Note: OIDs containing arcs that do not fit into a `u64` are not part of the hierarchy.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.5), ("x500", "X.500"));
+    /// registry.insert(oid!(2.5.29.17), ("subjectAltName", "X509v3 Subject Alternative Name"));
+    ///
+    /// let (parent, _entry) = registry.parent(&oid!(2.5.29.17)).expect("no parent");
+    /// assert_eq!(parent, &oid!(2.5));
+    /// ```
+    pub fn parent(&self, oid: &Oid) -> Option<(&Oid<'a>, &OidEntry)> {
+        self.ancestors(oid).next()
+    }
+
+    /// Return an iterator over the registered ancestors of `oid`, closest first
+    ///
+    /// `oid` itself is not included, and does not need to be registered.
+    pub fn ancestors(&self, oid: &Oid) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        let v = match oid.iter() {
+            Some(arcs) => self.tree.ancestors(arcs),
+            None => Vec::new(),
+        };
+        v.into_iter().filter_map(move |oid| self.get_pair(oid))
+    }
+
+    /// Return an iterator over the registered children of `oid`, in arc order
+    ///
+    /// Children are the registered OIDs below `oid` which have no other registered OID between
+    /// them and `oid`. For ex, if `2.5.29` is not registered, the children of `2.5` include
+    /// `2.5.29.17`.
+    ///
+    /// `oid` itself does not need to be registered.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(2.5.29.15), ("keyUsage", "X509v3 Key Usage"));
+    /// registry.insert(oid!(2.5.29.17), ("subjectAltName", "X509v3 Subject Alternative Name"));
+    ///
+    /// // list all X.509 extensions
+    /// for (oid, entry) in registry.children(&oid!(2.5.29)) {
+    ///     println!("{}: {}", oid, entry.sn());
+    /// }
+    /// ```
+    pub fn children(&self, oid: &Oid) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        self.iter_tree(oid, true)
+    }
+
+    /// Return an iterator over all the registered OIDs below `oid`, in depth-first arc order
+    ///
+    /// `oid` itself is not included, and does not need to be registered.
+    pub fn descendants(&self, oid: &Oid) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        self.iter_tree(oid, false)
+    }
+
+    fn iter_tree(&self, oid: &Oid, children_only: bool) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        let node = oid.iter().and_then(|arcs| self.tree.find(arcs));
+        node.into_iter()
+            .flat_map(move |node| node.descendants(children_only))
+            .filter_map(move |oid| self.get_pair(oid))
     }
 
     /// Populate registry with common crypto OIDs (encryption, hash algorithms)
@@ -247,15 +884,112 @@ impl<'a> OidRegistry<'a> {
     }
 }
 
+impl OidRegistry<'static> {
+    /// Insert a new entry, copying the OID if it borrows data
+    ///
+    /// This allows inserting OIDs with any lifetime (for ex. parsed from a temporary buffer) in a
+    /// `'static` registry.
+    pub fn insert_owned<E>(&mut self, oid: &Oid, entry: E) -> Option<OidEntry>
+    where
+        E: Into<OidEntry>,
+    {
+        self.insert(oid.to_owned(), entry)
+    }
+}
+
+impl<'a, E> Extend<(Oid<'a>, E)> for OidRegistry<'a>
+where
+    E: Into<OidEntry>,
+{
+    fn extend<T: IntoIterator<Item = (Oid<'a>, E)>>(&mut self, iter: T) {
+        for (oid, entry) in iter {
+            self.insert(oid, entry);
+        }
+    }
+}
+
+impl<'a, E> FromIterator<(Oid<'a>, E)> for OidRegistry<'a>
+where
+    E: Into<OidEntry>,
+{
+    fn from_iter<T: IntoIterator<Item = (Oid<'a>, E)>>(iter: T) -> Self {
+        let mut registry = OidRegistry::default();
+        registry.extend(iter);
+        registry
+    }
+}
+
 /// Format a OID to a `String`, using the provided registry to get the short name if present.
 pub fn format_oid(oid: &Oid, registry: &OidRegistry) -> String {
-    if let Some(entry) = registry.map.get(oid) {
+    if let Some(entry) = registry.get(oid) {
         format!("{} ({})", entry.sn, oid)
     } else {
         format!("{}", oid)
     }
 }
 
+/// Format a OID to a `String`, replacing its longest registered prefix by the short name.
+///
+/// For ex, if `2.5.4` is registered as `x509`, then `2.5.4.3` is formatted as `x509.3`. If `oid`
+/// is registered, only its short name is returned. If no prefix is registered, the dotted OID is
+/// returned.
+pub fn format_oid_partial(oid: &Oid, registry: &OidRegistry) -> String {
+    let arcs: Option<Vec<u64>> = oid.iter().map(Iterator::collect);
+    match arcs.as_deref().and_then(|arcs| registry.get_closest(arcs)) {
+        Some((_, entry, remaining)) => {
+            let mut s = entry.sn.to_string();
+            for arc in remaining {
+                s.push_str(&format!(".{}", arc));
+            }
+            s
+        }
+        None => format!("{}", oid),
+    }
+}
+
+/// Format a OID, given as the content octets of its DER encoding, to a `String`, using the
+/// provided registry to get the short name if present.
+///
+/// If `der` is not a valid encoding (truncated, or with a non-minimal sub-identifier), it is
+/// formatted as hex, for ex. `invalid OID (hex: 2a86)`. An empty slice is formatted as
+/// `invalid OID (empty)`.
+///
+/// See [`format_oid`] and [`OidRegistry::get_by_der`].
+pub fn format_oid_der(der: &[u8], registry: &OidRegistry) -> String {
+    if der.is_empty() {
+        return "invalid OID (empty)".to_string();
+    }
+    if !is_valid_oid_der(der) {
+        let hex: String = der.iter().map(|b| format!("{:02x}", b)).collect();
+        return format!("invalid OID (hex: {})", hex);
+    }
+    let oid = Oid::new(Cow::Borrowed(der));
+    match registry.get_by_der(der) {
+        Some(entry) => format!("{} ({})", entry.sn, oid),
+        None => format!("{}", oid),
+    }
+}
+
+// Check the content octets of an OID: each sub-identifier must be minimally encoded (no leading
+// 0x80 byte), and the last one must be complete
+fn is_valid_oid_der(der: &[u8]) -> bool {
+    let starts = core::iter::once(&0).chain(der).map(|b| b & 0x80 == 0);
+    let minimal = starts.zip(der).all(|(start, b)| !start || *b != 0x80);
+    minimal && der.last().map_or(false, |b| b & 0x80 == 0)
+}
+
+/// Format a OID, given as a list of arcs, to a `String`, using the provided registry to get the
+/// short name if present.
+///
+/// See [`format_oid`] and [`OidRegistry::get_by_arcs`].
+pub fn format_oid_arcs(arcs: &[u64], registry: &OidRegistry) -> String {
+    let dotted = arcs.iter().map(|arc| arc.to_string()).collect::<Vec<_>>().join(".");
+    match registry.get_by_arcs(arcs) {
+        Some(entry) => format!("{} ({})", entry.sn, dotted),
+        None => dotted,
+    }
+}
+
 include!(concat!(env!("OUT_DIR"), "/oid_db.rs"));
 
 #[rustfmt::skip::macros(oid)]
@@ -288,4 +1022,225 @@ mod tests {
 
         // dbg!(&registry);
     }
+
+    #[test]
+    fn test_arc_tree() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(2.5), ("x500", "X.500"));
+        registry.insert(oid!(2.5.4), ("x509", "X.509"));
+        registry.insert(oid!(2.5.4.3), ("commonName", "Common Name"));
+        registry.insert(oid!(2.5.29.15), ("keyUsage", "X509v3 Key Usage"));
+        registry.insert(oid!(2.5.29.17), ("subjectAltName", "X509v3 Subject Alternative Name"));
+        registry.insert(oid!(1.3.6.1.5.5.7.48.1), ("id-ad-ocsp", "PKIX Access Descriptor OCSP"));
+
+        let sn = |(_, e): (&Oid, &OidEntry)| e.sn().to_string();
+
+        assert_eq!(registry.parent(&oid!(2.5.29.17)).map(sn), Some("x500".to_string()));
+        assert_eq!(registry.parent(&oid!(2.5.4.3.1)).map(sn), Some("commonName".to_string()));
+        assert!(registry.parent(&oid!(2.5)).is_none());
+        assert!(registry.parent(&oid!(1.3.6.1.5.5.7.48)).is_none());
+
+        let ancestors: Vec<_> = registry.ancestors(&oid!(2.5.4.3)).map(sn).collect();
+        assert_eq!(ancestors, ["x509", "x500"]);
+
+        let children: Vec<_> = registry.children(&oid!(2.5)).map(sn).collect();
+        assert_eq!(children, ["x509", "keyUsage", "subjectAltName"]);
+        let children: Vec<_> = registry.children(&oid!(2.5.29)).map(sn).collect();
+        assert_eq!(children, ["keyUsage", "subjectAltName"]);
+        assert_eq!(registry.children(&oid!(1.3.6.1.5.5.7.48)).count(), 1);
+        assert_eq!(registry.children(&oid!(1.2.3)).count(), 0);
+
+        let descendants: Vec<_> = registry.descendants(&oid!(2.5)).map(sn).collect();
+        assert_eq!(descendants, ["x509", "commonName", "keyUsage", "subjectAltName"]);
+    }
+
+    #[test]
+    fn test_get_closest() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(2.5), ("x500", "X.500"));
+        registry.insert(oid!(2.5.4), ("x509", "X.509"));
+        registry.insert(oid!(2.5.4.3), ("commonName", "Common Name"));
+
+        let (oid, entry, remaining) = registry.get_closest(&[2, 5, 4, 99, 1]).expect("no match");
+        assert_eq!(oid, &oid!(2.5.4));
+        assert_eq!(entry.sn(), "x509");
+        assert_eq!(remaining, [99, 1]);
+
+        let (_, entry, remaining) = registry.get_closest(&[2, 5, 4, 3]).expect("no match");
+        assert_eq!(entry.sn(), "commonName");
+        assert!(remaining.is_empty());
+
+        assert!(registry.get_closest(&[1, 2, 3]).is_none());
+
+        assert_eq!(format_oid_partial(&oid!(2.5.4.99.1), &registry), "x509.99.1");
+        assert_eq!(format_oid_partial(&oid!(2.5.4.3), &registry), "commonName");
+        assert_eq!(format_oid_partial(&oid!(2.5.29.17), &registry), "x500.29.17");
+        assert_eq!(format_oid_partial(&oid!(1.2.3), &registry), "1.2.3");
+    }
+
+    #[test]
+    fn test_transient_oids() {
+        // This test is mostly a compile test: OIDs with a short lifetime can be used with a
+        // 'static registry, even through a mutable reference
+        fn update(registry: &mut OidRegistry<'static>, data: &[u8]) {
+            let oid = Oid::new(Cow::Borrowed(data));
+            if registry.contains(&oid) {
+                registry.remove(&oid);
+            } else {
+                registry.insert_owned(&oid, ("transient", "Transient OID"));
+            }
+        }
+
+        let mut registry = OidRegistry::default();
+        let data = vec![0x2a, 0x03, 0x04];
+        update(&mut registry, &data);
+        assert_eq!(registry.get(&oid!(1.2.3.4)).map(|e| e.sn()), Some("transient"));
+        update(&mut registry, &data);
+        assert!(registry.is_empty());
+
+        let borrowed: OidRegistry = vec![(Oid::new(Cow::Borrowed(&data[..])), ("borrowed", "Borrowed OID"))]
+            .into_iter()
+            .collect();
+        let owned: OidRegistry<'static> = borrowed.into_owned();
+        drop(data);
+        assert_eq!(owned.get_by_sn("borrowed").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.4)));
+    }
+
+    #[test]
+    fn test_get_by_der_and_arcs() {
+        let mut registry = OidRegistry::default();
+        registry.insert(
+            oid!(1.2.840.113549.1.1.11),
+            ("sha256WithRSAEncryption", "SHA256 with RSA encryption"),
+        );
+
+        let der = oid!(raw 1.2.840.113549.1.1.11);
+        assert_eq!(registry.get_by_der(&der).map(|e| e.sn()), Some("sha256WithRSAEncryption"));
+        assert!(registry.get_by_der(&der[..der.len() - 1]).is_none());
+        let arcs = [1, 2, 840, 113_549, 1, 1, 11];
+        assert_eq!(registry.get_by_arcs(&arcs).map(|e| e.sn()), Some("sha256WithRSAEncryption"));
+        assert!(registry.get_by_arcs(&arcs[..6]).is_none());
+        assert!(registry.get_by_arcs(&[]).is_none());
+
+        assert_eq!(format_oid_der(&der, &registry), "sha256WithRSAEncryption (1.2.840.113549.1.1.11)");
+        assert_eq!(format_oid_der(&[0x2a, 0x03], &registry), "1.2.3");
+        assert_eq!(format_oid_der(&[], &registry), "invalid OID (empty)");
+        assert_eq!(format_oid_der(&[0x80], &registry), "invalid OID (hex: 80)");
+        assert_eq!(format_oid_der(&[0xff, 0xff], &registry), "invalid OID (hex: ffff)");
+        assert_eq!(format_oid_der(&[0x2a, 0x80, 0x01], &registry), "invalid OID (hex: 2a8001)");
+        assert_eq!(format_oid_der(&[0x2a, 0x86], &registry), "invalid OID (hex: 2a86)");
+        assert_eq!(format_oid_arcs(&arcs, &registry), "sha256WithRSAEncryption (1.2.840.113549.1.1.11)");
+        assert_eq!(format_oid_arcs(&arcs[..6], &registry), "1.2.840.113549.1.1");
+    }
+
+    #[test]
+    fn test_sn_index() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.4), ("a", "first"));
+        registry.insert(oid!(1.2.3.5), ("b", "second"));
+        registry.insert(oid!(1.2.3.6), ("b", "third"));
+
+        assert_eq!(registry.iter_by_sn("a").count(), 1);
+        assert_eq!(registry.iter_by_sn(String::from("b")).count(), 2);
+        assert_eq!(registry.get_by_sn("a").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.4)));
+        assert!(registry.get_by_sn("c").unwrap().is_none());
+        assert_eq!(
+            registry.get_by_sn("b").unwrap_err(),
+            OidRegistryError::AmbiguousName {
+                name: "b".to_string(),
+                count: 2
+            }
+        );
+
+        // overwriting an entry updates the index
+        registry.insert(oid!(1.2.3.5), ("c", "second"));
+        assert_eq!(registry.get_by_sn("b").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.6)));
+        assert_eq!(registry.get_by_sn("c").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.5)));
+        registry.insert(oid!(1.2.3.4), ("a", "first, updated"));
+        assert_eq!(registry.iter_by_sn("a").count(), 1);
+    }
+
+    #[test]
+    fn test_entry_metadata() {
+        let entry = OidEntry::new("a", "A")
+            .with_alias("b")
+            .with_long_name("aLongName")
+            .with_reference("RFC 1234")
+            .with_status(OidStatus::Obsolete);
+        assert_eq!(entry.aliases().collect::<Vec<_>>(), ["b"]);
+        assert_eq!(entry.long_name(), Some("aLongName"));
+        assert_eq!(entry.references().collect::<Vec<_>>(), ["RFC 1234"]);
+        assert!(entry.status().is_deprecated());
+        assert_eq!(OidEntry::new_static("a", "A").status(), OidStatus::Current);
+
+        // metadata from the assets is carried to the generated tables
+        #[cfg(all(feature = "registry", feature = "x962", feature = "x509"))]
+        {
+            let registry = OidRegistry::default().with_x962().with_x509();
+            let (oid, entry) = registry.get_by_sn("P-256").unwrap().expect("alias not indexed");
+            assert_eq!(oid, &OID_EC_P256);
+            assert_eq!(entry.sn(), "prime256v1");
+            assert_eq!(entry.aliases().collect::<Vec<_>>(), ["secp256r1", "P-256"]);
+            assert_eq!(entry.references().collect::<Vec<_>>(), ["RFC 5480"]);
+            let entry = registry.get(&OID_X509_OBSOLETE_KEY_USAGE).unwrap();
+            assert_eq!(entry.status(), OidStatus::Obsolete);
+            assert_eq!(registry.get(&OID_X509_EXT_KEY_USAGE).unwrap().status(), OidStatus::Current);
+            let entry = registry.get(&OID_X509_ORGANIZATIONAL_UNIT).unwrap();
+            assert_eq!(entry.long_name(), Some("organizationalUnitName"));
+            assert!(StaticOidRegistry::new().iter_by_sn("secp256r1").any(|(oid, _)| oid == &OID_EC_P256));
+        }
+    }
+
+    #[test]
+    fn test_iter_by_kind() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.4), OidEntry::new("a", "A").with_kind(OidKind::HashAlgorithm));
+        registry.insert(oid!(1.2.3.5), ("b", "B"));
+        let hashes: Vec<_> = registry.iter_by_kind(OidKind::HashAlgorithm).map(|(oid, _)| oid).collect();
+        assert_eq!(hashes, [&oid!(1.2.3.4)]);
+        assert_eq!(registry.iter_by_kind(OidKind::Other).count(), 1);
+
+        #[cfg(all(feature = "registry", feature = "x962", feature = "nist_algs"))]
+        {
+            let registry = OidRegistry::default().with_x962().with_nist_algs();
+            let mut curves: Vec<_> = registry.iter_by_kind(OidKind::EllipticCurve).map(|(_, e)| e.sn()).collect();
+            curves.sort_unstable();
+            assert_eq!(curves, ["prime256v1", "secp384r1", "secp521r1"]);
+            assert_eq!(StaticOidRegistry::new().iter_by_kind(OidKind::EllipticCurve).count(), 3);
+        }
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut registry: OidRegistry = vec![
+            (oid!(1.2.3), ("a", "A")),
+            (oid!(1.2.3.4), ("b", "B")),
+            (oid!(1.2.3.4.5), ("c", "C")),
+            (oid!(1.2.3.4.6), ("c", "C")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(registry.len(), 4);
+        assert!(registry.contains(&oid!(1.2.3.4)));
+
+        assert_eq!(registry.remove(&oid!(1.2.3.4)).map(|e| e.sn().to_string()), Some("b".to_string()));
+        assert!(registry.remove(&oid!(1.2.3.4)).is_none());
+        assert!(!registry.contains(&oid!(1.2.3.4)));
+        assert_eq!(registry.iter_by_sn("b").count(), 0);
+        assert_eq!(registry.children(&oid!(1.2.3)).count(), 2);
+        assert_eq!(registry.parent(&oid!(1.2.3.4.5)).map(|(oid, _)| oid), Some(&oid!(1.2.3)));
+
+        registry.retain(|oid, _| oid != &oid!(1.2.3.4.5));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get_by_sn("c").unwrap().map(|(oid, _)| oid), Some(&oid!(1.2.3.4.6)));
+        assert_eq!(registry.descendants(&oid!(1.2.3)).count(), 1);
+
+        registry.extend(vec![(oid!(1.2.4), OidEntry::new("d", "D"))]);
+        assert_eq!(registry.len(), 3);
+
+        registry.clear();
+        assert!(registry.is_empty());
+        assert_eq!(registry.iter_by_sn("a").count(), 0);
+        assert_eq!(registry.descendants(&oid!(1.2)).count(), 0);
+    }
 }
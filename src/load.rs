@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Result, Write};
-use std::path::Path;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
 
 /// Temporary structure, created when reading a file containing OID declarations
-#[derive(Debug)]
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct LoadedEntry {
     /// Name of the global constant for this entry.
     ///
@@ -12,10 +13,42 @@ pub struct LoadedEntry {
     pub name: String,
     /// Textual representation of OID (ex: 2.5.4.3)
     pub oid: String,
-    /// A short name to describe OID. Should be unique (no check is done)
+    /// A short name to describe OID. Should be unique (checked by [`validate`])
     pub sn: String,
     /// A description for this entry
     pub description: String,
+    /// Alternative short names (attribute `alias`)
+    pub aliases: Vec<String>,
+    /// Long name (attribute `ln`)
+    pub long_name: Option<String>,
+    /// Specification references (attribute `ref`)
+    pub references: Vec<String>,
+    /// Kind of the OID, for ex. `signature` or `curve` (attribute `kind`)
+    pub kind: Option<String>,
+    /// Status of the OID: `current`, `deprecated` or `obsolete` (attribute `status`)
+    pub status: Option<String>,
+    /// For signature algorithms, short name of the hash algorithm (attribute `hash`)
+    pub hash: Option<String>,
+    /// For signature algorithms, short name of the public key type (attribute `key`)
+    pub key: Option<String>,
+    /// For algorithms, expected form of the `AlgorithmIdentifier` parameters (attribute `params`)
+    pub params: Option<String>,
+    /// For hash algorithms, the family, for ex. `sha2` (attribute `family`)
+    pub family: Option<String>,
+    /// For hash algorithms, the size of the digest in bytes (attribute `digest-size`)
+    pub digest_size: Option<usize>,
+    /// For hash algorithms, the size of the input block in bytes (attribute `block-size`)
+    pub block_size: Option<usize>,
+    /// For hash algorithms, `true` if the output length is variable (attribute `xof`)
+    pub xof: bool,
+    /// For elliptic curves, the size of the field in bits (attribute `field-bits`)
+    pub field_bits: Option<usize>,
+    /// For elliptic curves, the form of the curve, for ex. `weierstrass` (attribute `curve-form`)
+    pub curve_form: Option<String>,
+    /// For elliptic curves, the security level in bits (attribute `security-bits`)
+    pub security_bits: Option<usize>,
+    /// For algorithms, the security strength in bits (attribute `strength`)
+    pub strength: Option<usize>,
 }
 
 /// Temporary structure, created when reading a file containing OID declarations
@@ -25,50 +58,626 @@ pub type LoadedMap = BTreeMap<String, Vec<LoadedEntry>>;
 ///
 /// format of the file: tab-separated values
 /// <pre>
-/// feature   name   oid   short_name   description (until end of line)
+/// feature   name   oid   short_name   description   [key=value ...]
 /// </pre>
 ///
 /// `name` is used to declare a global constant when creating output file (see `generate_file`).
 /// If `name` is "" then no constant will be written
 ///
-pub fn load_file<P: AsRef<Path>>(path: P) -> Result<LoadedMap> {
+/// The description can be followed by optional attributes, each in its own column:
+///
+/// - `alias=<name>`: an alternative short name (can be repeated)
+/// - `ln=<name>`: the long name
+/// - `ref=<reference>`: a specification reference, for ex. `RFC 5280` or a URL (can be repeated)
+/// - `kind=<kind>`: the category of the OID: `signature`, `hash`, `public-key`, `curve`,
+///   `encryption`, `kdf`, `extension`, `name-attribute`, `attribute`, `content-type`,
+///   `access-method`, `key-purpose`, `arc` or `other` (default)
+/// - `status=<status>`: `current` (default), `deprecated` or `obsolete`
+/// - `hash=<sn>`, `key=<sn>`: for signature algorithms, the short names of the hash algorithm and
+///   of the public key type. Both must be set.
+/// - `params=<form>`: for algorithms, the expected form of the `AlgorithmIdentifier` parameters:
+///   `absent`, `null`, `absent-or-null`, `required:<type>` or `optional:<type>` (absent, or
+///   present with the given type) where `<type>` is one of `sequence`, `oid`, `octet-string` or
+///   `integer`
+/// - `family=<family>`, `digest-size=<bytes>`, `block-size=<bytes>`: for hash algorithms, the
+///   family (`md`, `sha1`, `sha2`, `sha3`, `gost` or `ripemd`), the size of the digest and the
+///   size of the input block. All three must be set.
+/// - `xof=true`: for hash algorithms, the output length is variable (extendable-output
+///   function). The digest size is then the default output length.
+/// - `field-bits=<bits>`, `curve-form=<form>`, `security-bits=<bits>`: for elliptic curves, the
+///   size of the field, the form of the curve (`weierstrass`, `edwards` or `montgomery`) and the
+///   security level. All three must be set.
+/// - `strength=<bits>`: for algorithms, the security strength (see NIST SP 800-57). For signature
+///   algorithms, this is the strength of the hash algorithm. `0` means the algorithm is broken.
+///
+/// Loading stops at the first invalid line, see [`load_file_lenient`] to skip invalid lines.
+pub fn load_file<P: AsRef<Path>>(path: P) -> Result<LoadedMap, LoadError> {
+    let path = path.as_ref();
+    load_map(open_file(path)?, Some(path), None)
+}
+
+/// Load a file to an OID description map, skipping invalid lines
+///
+/// Unlike [`load_file`], invalid lines do not stop the loading: they are skipped, and the errors
+/// are returned with the loaded map. Only I/O errors are fatal.
+///
+/// See [`load_file`] for the file format.
+pub fn load_file_lenient<P: AsRef<Path>>(path: P) -> Result<(LoadedMap, Vec<LoadError>), LoadError> {
+    let path = path.as_ref();
+    let mut diagnostics = Vec::new();
+    let map = load_map(open_file(path)?, Some(path), Some(&mut diagnostics))?;
+    Ok((map, diagnostics))
+}
+
+/// Load OID declarations from a reader to an OID description map
+///
+/// This is the same as [`load_file`], except that errors do not have a path.
+pub fn load_reader<R: BufRead>(reader: R) -> Result<LoadedMap, LoadError> {
+    load_map(reader, None, None)
+}
+
+pub(crate) fn open_file(path: &Path) -> Result<BufReader<File>, LoadError> {
+    let file = File::open(path).map_err(|error| LoadError::Io {
+        path: Some(path.to_path_buf()),
+        error,
+    })?;
+    Ok(BufReader::new(file))
+}
+
+fn load_map<R: BufRead>(reader: R, path: Option<&Path>, diagnostics: Option<&mut Vec<LoadError>>) -> Result<LoadedMap, LoadError> {
     let mut map = BTreeMap::new();
+    load_entries(reader, path, diagnostics, |feature, entry| {
+        map.entry(feature).or_insert_with(Vec::new).push(entry);
+        Ok(())
+    })?;
+    Ok(map)
+}
 
-    let file = File::open(path)?;
-    for line in BufReader::new(file).lines() {
-        let line = line?;
+// Load OID declarations from a reader, calling `f` for each entry. `path` is only used in errors.
+// If `diagnostics` is set, invalid lines are skipped and errors are pushed to it.
+pub(crate) fn load_entries<R, F>(
+    reader: R,
+    path: Option<&Path>,
+    mut diagnostics: Option<&mut Vec<LoadError>>,
+    mut f: F,
+) -> Result<(), LoadError>
+where
+    R: BufRead,
+    F: FnMut(String, LoadedEntry) -> Result<(), LineError>,
+{
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|error| LoadError::Io {
+            path: path.map(Path::to_path_buf),
+            error,
+        })?;
         if line.is_empty() || line.starts_with('#') {
             continue;
         }
-        // split by tabs
-        let mut iter = line.splitn(5, '\t');
-        let feature = iter.next().expect("invalid oid_db format: missing feature").replace('-', "_");
-        let name = iter.next().expect("invalid oid_db format: missing name").to_string();
-        let oid = iter.next().expect("invalid oid_db format: missing OID").to_string();
-        let sn = iter.next().expect("invalid oid_db format: missing short name").to_string();
-        let description = iter.next().expect("invalid oid_db format: missing description").to_string();
-
-        let entry = LoadedEntry {
-            name,
-            oid,
-            sn,
-            description,
+        if let Err(e) = parse_line(&line).and_then(|(feature, entry)| f(feature, entry)) {
+            let e = e.into_error(path, idx + 1, &line);
+            match diagnostics.as_mut() {
+                Some(diagnostics) => diagnostics.push(e),
+                None => return Err(e),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An error encountered when loading a file containing OID declarations
+///
+/// Line and column numbers start at 1. Columns are the tab-separated fields of a line. The path
+/// is not set when loading from a reader.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read
+    Io {
+        /// Path of the file
+        path: Option<PathBuf>,
+        /// The I/O error
+        error: io::Error,
+    },
+    /// A mandatory field is missing
+    MissingField {
+        /// Path of the file
+        path: Option<PathBuf>,
+        /// Line number
+        line: usize,
+        /// Column of the missing field
+        column: usize,
+        /// Name of the missing field
+        field: &'static str,
+        /// Text of the line
+        text: String,
+    },
+    /// A field or attribute has an invalid value
+    InvalidField {
+        /// Path of the file
+        path: Option<PathBuf>,
+        /// Line number
+        line: usize,
+        /// Column of the field
+        column: usize,
+        /// Name of the field, or key of the attribute
+        field: String,
+        /// Text of the field
+        text: String,
+    },
+    /// An attribute has an unknown key
+    UnknownAttribute {
+        /// Path of the file
+        path: Option<PathBuf>,
+        /// Line number
+        line: usize,
+        /// Column of the attribute
+        column: usize,
+        /// Text of the attribute
+        text: String,
+    },
+}
+
+impl LoadError {
+    /// Get the path of the file, if loading from a file
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            LoadError::Io { path, .. }
+            | LoadError::MissingField { path, .. }
+            | LoadError::InvalidField { path, .. }
+            | LoadError::UnknownAttribute { path, .. } => path.as_deref(),
+        }
+    }
+
+    /// Get the line number of the error, if the error is related to a line
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            LoadError::Io { .. } => None,
+            LoadError::MissingField { line, .. } | LoadError::InvalidField { line, .. } | LoadError::UnknownAttribute { line, .. } => {
+                Some(*line)
+            }
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = |f: &mut fmt::Formatter, line: &usize, column: &usize| match self.path() {
+            Some(path) => write!(f, "{}:{}:{}: ", path.display(), line, column),
+            None => write!(f, "line {}, column {}: ", line, column),
+        };
+        match self {
+            LoadError::Io { path: Some(path), error } => write!(f, "{}: {}", path.display(), error),
+            LoadError::Io { path: None, error } => write!(f, "{}", error),
+            LoadError::MissingField {
+                line, column, field, text, ..
+            } => {
+                location(f, line, column)?;
+                write!(f, "missing field '{}' in line '{}'", field, text)
+            }
+            LoadError::InvalidField {
+                line, column, field, text, ..
+            } => {
+                location(f, line, column)?;
+                write!(f, "invalid value for '{}': '{}'", field, text)
+            }
+            LoadError::UnknownAttribute { line, column, text, .. } => {
+                location(f, line, column)?;
+                write!(f, "unknown attribute '{}'", text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Error in a line, without its location
+pub(crate) enum LineError {
+    Missing(usize, &'static str),
+    Invalid(usize, String, String),
+    UnknownAttribute(usize, String),
+}
+
+impl LineError {
+    fn into_error(self, path: Option<&Path>, line: usize, line_text: &str) -> LoadError {
+        let path = path.map(Path::to_path_buf);
+        match self {
+            LineError::Missing(column, field) => LoadError::MissingField {
+                path,
+                line,
+                column,
+                field,
+                text: line_text.to_string(),
+            },
+            LineError::Invalid(column, field, text) => LoadError::InvalidField {
+                path,
+                line,
+                column,
+                field,
+                text,
+            },
+            LineError::UnknownAttribute(column, text) => LoadError::UnknownAttribute { path, line, column, text },
+        }
+    }
+}
+
+// Parse a line to a feature name and an entry
+fn parse_line(line: &str) -> Result<(String, LoadedEntry), LineError> {
+    // split by tabs
+    let columns: Vec<&str> = line.split('\t').collect();
+    let field = |idx: usize, name: &'static str| match columns.get(idx) {
+        Some(text) if !text.is_empty() || name == "description" => Ok(*text),
+        _ => Err(LineError::Missing(idx + 1, name)),
+    };
+    let feature = field(0, "feature")?;
+    let name = field(1, "name")?;
+    let oid = field(2, "oid")?;
+    if !is_valid_oid(oid) {
+        return Err(LineError::Invalid(3, "oid".to_string(), oid.to_string()));
+    }
+    let sn = field(3, "short name")?;
+    let description = field(4, "description")?;
+
+    let mut entry = LoadedEntry {
+        name: name.to_string(),
+        oid: oid.to_string(),
+        sn: sn.to_string(),
+        description: description.to_string(),
+        ..Default::default()
+    };
+    for (column, attr) in columns.iter().enumerate().skip(5).filter(|(_, attr)| !attr.is_empty()) {
+        let column = column + 1;
+        let (key, value) = match attr.split_once('=') {
+            Some(kv) => kv,
+            None => return Err(LineError::Invalid(column, "attribute".to_string(), attr.to_string())),
         };
+        let invalid = || LineError::Invalid(column, key.to_string(), value.to_string());
+        let checked = |valid: bool| if valid { Ok(Some(value.to_string())) } else { Err(invalid()) };
+        match key {
+            "alias" => entry.aliases.push(value.to_string()),
+            "ln" => entry.long_name = Some(value.to_string()),
+            "ref" => entry.references.push(value.to_string()),
+            "kind" => entry.kind = checked(kind_variant(value).is_some())?,
+            "status" => entry.status = checked(status_variant(value).is_some())?,
+            _ => set_table_attribute(&mut entry, column, key, value)?,
+        }
+    }
 
-        let v = map.entry(feature.to_string()).or_insert_with(Vec::new);
+    Ok((feature.replace('-', "_"), entry))
+}
 
-        v.push(entry);
+// Set an attribute only used to generate the static tables (for ex. `strength`)
+pub(crate) fn set_table_attribute(entry: &mut LoadedEntry, column: usize, key: &str, value: &str) -> Result<(), LineError> {
+    let invalid = || LineError::Invalid(column, key.to_string(), value.to_string());
+    let parse_number = |value: &str| value.parse::<usize>().map_err(|_| invalid());
+    let checked = |valid: bool| if valid { Ok(Some(value.to_string())) } else { Err(invalid()) };
+    match key {
+        "family" => entry.family = checked(hash_family_variant(value).is_some())?,
+        "digest-size" => entry.digest_size = Some(parse_number(value)?),
+        "block-size" => entry.block_size = Some(parse_number(value)?),
+        "xof" => entry.xof = value.parse().map_err(|_| invalid())?,
+        "field-bits" => entry.field_bits = Some(parse_number(value)?),
+        "curve-form" => entry.curve_form = checked(curve_form_variant(value).is_some())?,
+        "security-bits" => entry.security_bits = Some(parse_number(value)?),
+        "strength" => entry.strength = Some(parse_number(value)?),
+        "hash" => entry.hash = Some(value.to_string()),
+        "key" => entry.key = Some(value.to_string()),
+        "params" => entry.params = checked(parameter_expectation_code(value).is_some())?,
+        _ => return Err(LineError::UnknownAttribute(column, format!("{}={}", key, value))),
     }
-    Ok(map)
+    Ok(())
+}
+
+// Set attributes only used to generate the static tables, in the order used in the assets
+pub(crate) fn table_attributes(entry: &LoadedEntry) -> Vec<(&'static str, String)> {
+    let attributes = [
+        ("strength", entry.strength.map(|n| n.to_string())),
+        ("family", entry.family.clone()),
+        ("digest-size", entry.digest_size.map(|n| n.to_string())),
+        ("block-size", entry.block_size.map(|n| n.to_string())),
+        ("xof", if entry.xof { Some("true".to_string()) } else { None }),
+        ("field-bits", entry.field_bits.map(|n| n.to_string())),
+        ("curve-form", entry.curve_form.clone()),
+        ("security-bits", entry.security_bits.map(|n| n.to_string())),
+        ("hash", entry.hash.clone()),
+        ("key", entry.key.clone()),
+        ("params", entry.params.clone()),
+    ];
+    attributes.iter().filter_map(|(key, value)| Some((*key, value.clone()?))).collect()
+}
+
+// Check that a string is a valid dotted OID (ex: 2.5.4.3)
+fn is_valid_oid(oid: &str) -> bool {
+    oid_syntax_error(oid).is_none()
+}
+
+// Describe why a string is not a valid dotted OID, if it is not
+fn oid_syntax_error(oid: &str) -> Option<String> {
+    let mut arcs = Vec::new();
+    for arc in oid.split('.') {
+        if arc.is_empty() || !arc.bytes().all(|b| b.is_ascii_digit()) {
+            return Some(format!("invalid arc '{}'", arc));
+        }
+        if arc.len() > 1 && arc.starts_with('0') {
+            return Some(format!("arc '{}' has leading zeros", arc));
+        }
+        match arc.parse::<u64>() {
+            Ok(arc) => arcs.push(arc),
+            Err(_) => return Some(format!("arc '{}' is too large", arc)),
+        }
+    }
+    match arcs.as_slice() {
+        [_] => Some("an OID must have at least two arcs".to_string()),
+        [first, ..] if *first > 2 => Some(format!("first arc must be 0, 1 or 2, found {}", first)),
+        [first, second, ..] if *first < 2 && *second >= 40 => {
+            Some(format!("second arc must be lower than 40 below arc {}, found {}", first, second))
+        }
+        // the first two arcs are encoded as a single arc
+        [first, second, ..] if first_arcs(*first, *second).is_none() => Some(format!("second arc '{}' is too large", second)),
+        _ => None,
+    }
+}
+
+/// Severity of a [`Diagnostic`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The entry is suspicious, but valid code can be generated
+    Warning,
+    /// The entry is invalid: code generation would fail, or generate invalid code
+    Error,
+}
+
+/// A problem found by [`validate`] in an OID description map
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// Severity of the problem
+    pub severity: Severity,
+    /// Feature of the entry
+    pub feature: String,
+    /// Textual representation of the OID of the entry, if the problem is related to an entry
+    pub oid: Option<String>,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, feature: &str, oid: Option<&str>, message: String) -> Self {
+        Diagnostic {
+            severity,
+            feature: feature.to_string(),
+            oid: oid.map(str::to_string),
+            message,
+        }
+    }
+
+    /// Return `true` if the problem prevents generating code
+    pub fn is_fatal(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        match &self.oid {
+            Some(oid) => write!(f, "{}: {} (feature {}): {}", severity, oid, self.feature, self.message),
+            None => write!(f, "{}: feature {}: {}", severity, self.feature, self.message),
+        }
+    }
+}
+
+/// Check an OID description map for invalid or suspicious entries
+///
+/// The following checks are done:
+///
+/// - feature names are lowercase identifiers and, if `features` is set, are in this list (usually
+///   the features declared in `Cargo.toml`, otherwise the entries would never be compiled)
+/// - OIDs are valid (syntax and arc constraints), and unique across all features
+/// - constant names are valid Rust identifiers, unique, and in upper case
+/// - short names and aliases are unique across all features (duplicates are only warnings:
+///   lookups by short name then return several entries)
+/// - fields do not have leading or trailing whitespace
+/// - grouped attributes (`hash` and `key`, hash algorithm and elliptic curve properties) are
+///   complete, and `hash` and `key` refer to known and unique short names
+///
+/// Fatal diagnostics (see [`Diagnostic::is_fatal`]) must be fixed before calling
+/// [`generate_file`] or [`generate_tables`].
+pub fn validate(map: &LoadedMap, features: Option<&[&str]>) -> Vec<Diagnostic> {
+    use Severity::{Error, Warning};
+
+    let mut diagnostics = Vec::new();
+    let mut oids: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut names: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut sns: BTreeMap<&str, &str> = BTreeMap::new();
+    let mut ambiguous_sns = std::collections::BTreeSet::new();
+    for (feature, entries) in map {
+        if !is_valid_feature_name(feature) {
+            let message = format!("feature name '{}' should be a lowercase identifier", feature);
+            diagnostics.push(Diagnostic::new(Error, feature, None, message));
+        } else if features.map_or(false, |features| !features.contains(&feature.as_str())) {
+            let message = format!("feature '{}' is not declared", feature);
+            diagnostics.push(Diagnostic::new(Error, feature, None, message));
+        }
+        for entry in entries {
+            let oid = entry.oid.as_str();
+            let mut report = |severity, message| diagnostics.push(Diagnostic::new(severity, feature, Some(oid), message));
+
+            // whitespace
+            let fields = [
+                ("name", &entry.name),
+                ("oid", &entry.oid),
+                ("short name", &entry.sn),
+                ("description", &entry.description),
+            ];
+            let attributes = entry.aliases.iter().map(|alias| ("alias", alias));
+            let attributes = attributes.chain(entry.long_name.iter().map(|ln| ("ln", ln)));
+            let attributes = attributes.chain(entry.references.iter().map(|reference| ("ref", reference)));
+            for (field, value) in fields.iter().copied().chain(attributes) {
+                if value.trim() != value {
+                    let severity = if field == "name" || field == "oid" { Error } else { Warning };
+                    report(severity, format!("{} {:?} has leading or trailing whitespace", field, value));
+                }
+            }
+
+            // OID
+            if let Some(message) = oid_syntax_error(oid) {
+                report(Error, format!("invalid OID: {}", message));
+            }
+            if let Some(previous) = oids.insert(oid, feature) {
+                report(Error, format!("duplicate OID, already declared in feature {}", previous));
+            }
+
+            // constant name
+            let name = entry.name.as_str();
+            if name != "\"\"" && name.trim() == name {
+                if !is_valid_identifier(name) {
+                    report(Error, format!("constant name '{}' is not a valid identifier", name));
+                } else if name.bytes().any(|b| b.is_ascii_lowercase()) {
+                    report(Warning, format!("constant name '{}' should be in upper case", name));
+                }
+                if let Some(previous) = names.insert(name, oid) {
+                    report(Error, format!("duplicate constant name '{}', already used by {}", name, previous));
+                }
+            }
+
+            // short names
+            for sn in std::iter::once(&entry.sn).chain(&entry.aliases) {
+                match sns.get(sn.as_str()) {
+                    Some(previous) if *previous != oid => {
+                        report(Warning, format!("duplicate short name '{}', already used by {}", sn, previous));
+                        ambiguous_sns.insert(sn.as_str());
+                    }
+                    Some(_) => (),
+                    None => {
+                        sns.insert(sn, oid);
+                    }
+                }
+            }
+        }
+    }
+
+    // attribute groups, checked once all short names are known
+    for (feature, entries) in map {
+        for entry in entries {
+            let mut report = |message| diagnostics.push(Diagnostic::new(Error, feature, Some(&entry.oid), message));
+            match (&entry.hash, &entry.key) {
+                (Some(hash), Some(key)) => {
+                    for sn in [hash, key] {
+                        if !sns.contains_key(sn.as_str()) {
+                            report(format!("unknown short name '{}' in signature components", sn));
+                        } else if ambiguous_sns.contains(sn.as_str()) {
+                            report(format!("ambiguous short name '{}' in signature components", sn));
+                        }
+                    }
+                }
+                (None, None) => (),
+                _ => report("both hash and key must be set".to_string()),
+            }
+            let hash_attributes = [entry.family.is_some(), entry.digest_size.is_some(), entry.block_size.is_some()];
+            if hash_attributes.contains(&true) && hash_attributes.contains(&false) {
+                report("family, digest-size and block-size must be set together".to_string());
+            }
+            let curve_attributes = [
+                entry.field_bits.is_some(),
+                entry.curve_form.is_some(),
+                entry.security_bits.is_some(),
+            ];
+            if curve_attributes.contains(&true) && curve_attributes.contains(&false) {
+                report("field-bits, curve-form and security-bits must be set together".to_string());
+            }
+        }
+    }
+    diagnostics
+}
+
+// Check that a string is a valid Rust identifier (ASCII only)
+fn is_valid_identifier(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    match bytes.next() {
+        Some(b) if b.is_ascii_alphabetic() || b == b'_' => s != "_" && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_'),
+        _ => false,
+    }
+}
+
+// Check that a feature name is a lowercase identifier (ex: nist_algs)
+fn is_valid_feature_name(s: &str) -> bool {
+    is_valid_identifier(s) && !s.bytes().any(|b| b.is_ascii_uppercase())
+}
+
+/// Write an OID description map in the format of the assets files
+///
+/// Entries of all features are sorted by OID arcs, and `_` is replaced by `-` in feature names.
+/// Attributes are written only when set, in the order used in `assets/oid_db.txt`. The output is
+/// deterministic, and can be read back using [`load_file`] or [`load_reader`] to get the same
+/// map.
+///
+/// An error of kind [`io::ErrorKind::InvalidData`] is returned if an entry cannot be represented:
+/// if a field contains a tab or a line break, or if a mandatory field is empty.
+pub fn write_map<W: Write>(map: &LoadedMap, mut writer: W) -> io::Result<()> {
+    let mut entries: Vec<_> = map
+        .iter()
+        .flat_map(|(feature, entries)| entries.iter().map(move |entry| (feature, entry)))
+        .collect();
+    entries.sort_by_cached_key(|(_, entry)| (oid_sort_key(&entry.oid), entry.oid.clone()));
+    for (feature, entry) in entries {
+        write_entry(&mut writer, &feature.replace('_', "-"), entry)?;
+    }
+    Ok(())
+}
+
+// Key to sort dotted OIDs by arcs (invalid arcs are sorted last)
+fn oid_sort_key(oid: &str) -> Vec<u64> {
+    oid.split('.').map(|arc| arc.parse().unwrap_or(u64::MAX)).collect()
+}
+
+fn write_entry<W: Write>(writer: &mut W, feature: &str, entry: &LoadedEntry) -> io::Result<()> {
+    let mut columns = vec![
+        feature.to_string(),
+        entry.name.clone(),
+        entry.oid.clone(),
+        entry.sn.clone(),
+        entry.description.clone(),
+    ];
+    // same order as in the assets file
+    let kind = entry.kind.iter().map(|kind| ("kind", kind.clone()));
+    let status = entry.status.iter().map(|status| ("status", status.clone()));
+    let long_name = entry.long_name.iter().map(|ln| ("ln", ln.clone()));
+    let attributes = kind.chain(table_attributes(entry)).chain(status).chain(long_name);
+    columns.extend(attributes.map(|(key, value)| format!("{}={}", key, value)));
+    columns.extend(entry.aliases.iter().map(|alias| format!("alias={}", alias)));
+    columns.extend(entry.references.iter().map(|reference| format!("ref={}", reference)));
+
+    for (idx, column) in columns.iter().enumerate() {
+        // the description (5th column) is the only field which can be empty, and lines starting
+        // with '#' are comments
+        let invalid = column.contains(&['\t', '\n', '\r'][..]) || (column.is_empty() && idx != 4) || (idx == 0 && column.starts_with('#'));
+        if invalid {
+            let message = format!("cannot write column {} of entry {}: {:?}", idx + 1, entry.oid, column);
+            return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+        }
+    }
+    writeln!(writer, "{}", columns.join("\t"))
 }
 
 /// Generate a file containing a `with_<feat>` method for OidRegistry
-pub fn generate_file<P: AsRef<Path>>(map: &LoadedMap, dest_path: P) -> Result<()> {
+pub fn generate_file<P: AsRef<Path>>(map: &LoadedMap, dest_path: P) -> io::Result<()> {
     let mut out_file = File::create(&dest_path)?;
     for feat_entries in map.values() {
         for v in feat_entries {
             if v.name != "\"\"" {
-                writeln!(out_file, "/// {}", v.oid)?;
+                writeln!(out_file, "#[doc = {:?}]", v.oid)?;
+                if let Some(status) = &v.status {
+                    writeln!(out_file, r#"#[doc = ""]"#)?;
+                    writeln!(out_file, "#[doc = {:?}]", format!("Status: {}", status))?;
+                }
                 writeln!(out_file, "pub const {}: Oid<'static> = oid!({});", v.name, v.oid)?;
             }
         }
@@ -77,7 +686,7 @@ pub fn generate_file<P: AsRef<Path>>(map: &LoadedMap, dest_path: P) -> Result<()
     writeln!(out_file, r#"#[cfg(feature = "registry")]"#)?;
     writeln!(out_file, r#"#[cfg_attr(docsrs, doc(cfg(feature = "registry")))]"#)?;
     writeln!(out_file, "impl<'a> OidRegistry<'a> {{")?;
-    for (k, v) in map {
+    for k in map.keys() {
         writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
         writeln!(out_file, r#"    #[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#, k)?;
         writeln!(
@@ -86,17 +695,704 @@ pub fn generate_file<P: AsRef<Path>>(map: &LoadedMap, dest_path: P) -> Result<()
             k
         )?;
         writeln!(out_file, "    pub fn with_{}(mut self) -> Self {{", k)?;
-        for item in v {
+        writeln!(out_file, "        for (oid, entry) in {}.entries {{", static_table_name(k))?;
+        writeln!(out_file, "            self.insert(oid.clone(), entry.clone());")?;
+        writeln!(out_file, "        }}")?;
+        writeln!(out_file, "        self")?;
+        writeln!(out_file, "    }}")?;
+        writeln!(out_file)?;
+    }
+    writeln!(
+        out_file,
+        r#"    #[doc = "Load all known OIDs for all enabled features in the registry."]"#
+    )?;
+    writeln!(out_file, "    #[allow(unused_mut)]")?;
+    writeln!(out_file, "    pub fn with_all_features(mut self) -> Self {{")?;
+    for k in map.keys() {
+        writeln!(out_file, r#"        #[cfg(feature = "{}")]"#, k)?;
+        writeln!(out_file, "        {{")?;
+        writeln!(out_file, "            self = self.with_{}();", k)?;
+        writeln!(out_file, "        }}")?;
+    }
+    writeln!(out_file, "        self")?;
+    writeln!(out_file, "    }}")?;
+    writeln!(out_file, "}}")?;
+    Ok(())
+}
+
+/// Generate a file containing the static tables of known OIDs, for each feature
+///
+/// Entries of each table are sorted by the DER encoding of the OID, so they can be searched
+/// using a binary search.
+pub fn generate_tables<P: AsRef<Path>>(map: &LoadedMap, dest_path: P) -> io::Result<()> {
+    let mut out_file = File::create(&dest_path)?;
+    for (k, v) in map {
+        let mut entries: Vec<_> = v.iter().map(|item| (encode_oid_der(&item.oid), item)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut names: Vec<_> = entries
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, (_, item))| std::iter::once(&item.sn).chain(&item.aliases).map(move |name| (name, idx)))
+            .collect();
+        names.sort();
+
+        writeln!(out_file, r#"#[cfg(feature = "{}")]"#, k)?;
+        writeln!(out_file, "pub(crate) static {}: StaticTable = StaticTable {{", static_table_name(k))?;
+        writeln!(out_file, "    entries: &[")?;
+        for (_, item) in &entries {
+            let constant_name = if item.name == "\"\"" { None } else { Some(&item.name) };
+            let attributes: Vec<_> = table_attributes(item)
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "(alloc::borrow::Cow::Borrowed({:?}), alloc::borrow::Cow::Borrowed({:?}))",
+                        key, value
+                    )
+                })
+                .collect();
             writeln!(
                 out_file,
-                r#"        self.insert(oid!({}), OidEntry::new("{}", "{}"));"#,
-                item.oid, item.sn, item.description
+                r#"        (asn1_rs::oid!({}), OidEntry::from_static_parts({:?}, {:?}, &[{}], {:?}, &[{}], OidKind::{}, crate::OidStatus::{}, Some({:?}), {:?}, &[{}])),"#,
+                item.oid,
+                item.sn,
+                item.description,
+                static_cow_list(&item.aliases),
+                item.long_name,
+                static_cow_list(&item.references),
+                kind_variant(item.kind.as_deref().unwrap_or("other")).expect("invalid oid_db format: unknown kind"),
+                status_variant(item.status.as_deref().unwrap_or("current")).expect("invalid oid_db format: unknown status"),
+                k,
+                constant_name,
+                attributes.join(", ")
             )?;
         }
-        writeln!(out_file, "        self")?;
-        writeln!(out_file, "    }}")?;
+        writeln!(out_file, "    ],")?;
+        writeln!(out_file, "    by_sn: &[")?;
+        for (sn, idx) in names {
+            writeln!(out_file, "        ({:?}, {}),", sn, idx)?;
+        }
+        writeln!(out_file, "    ],")?;
+        writeln!(out_file, "}};")?;
         writeln!(out_file)?;
     }
-    writeln!(out_file, "}}")?;
+    writeln!(out_file, "static STATIC_TABLES: &[&StaticTable] = &[")?;
+    for k in map.keys() {
+        writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
+        writeln!(out_file, "    &{},", static_table_name(k))?;
+    }
+    writeln!(out_file, "];")?;
+    writeln!(out_file)?;
+    generate_signature_table(map, &mut out_file)?;
+    writeln!(out_file)?;
+    generate_parameter_table(map, &mut out_file)?;
+    writeln!(out_file)?;
+    generate_hash_table(map, &mut out_file)?;
+    writeln!(out_file)?;
+    generate_curve_table(map, &mut out_file)?;
+    writeln!(out_file)?;
+    generate_strength_table(map, &mut out_file)
+}
+
+// Write the table of security strengths of algorithms
+fn generate_strength_table<W: Write>(map: &LoadedMap, out_file: &mut W) -> io::Result<()> {
+    writeln!(out_file, "pub(crate) static SECURITY_STRENGTHS: &[(Oid<'static>, usize)] = &[")?;
+    for (k, v) in map {
+        for item in v {
+            if let Some(strength) = item.strength {
+                writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
+                writeln!(out_file, "    (asn1_rs::oid!({}), {}),", item.oid, strength)?;
+            }
+        }
+    }
+    writeln!(out_file, "];")?;
+    Ok(())
+}
+
+// Write the table of signature algorithms and their (hash, key type) components
+//
+// Components are given by short name in the assets, and resolved to OIDs across all features.
+// Rows are only compiled if the features of the signature, hash and key type are all enabled.
+// Obsolete signature OIDs are written last, so reverse lookups find current OIDs first.
+fn generate_signature_table<W: Write>(map: &LoadedMap, out_file: &mut W) -> io::Result<()> {
+    let mut oids_by_sn: BTreeMap<&str, Vec<(&str, &str)>> = BTreeMap::new();
+    for (k, v) in map {
+        for item in v {
+            for sn in std::iter::once(&item.sn).chain(&item.aliases) {
+                oids_by_sn.entry(sn).or_default().push((&item.oid, k));
+            }
+        }
+    }
+    let resolve = |sn: &str| match oids_by_sn.get(sn).map(|v| v.as_slice()) {
+        Some([oid]) => *oid,
+        Some(_) => panic!("invalid oid_db format: ambiguous short name '{}' in signature components", sn),
+        None => panic!("invalid oid_db format: unknown short name '{}' in signature components", sn),
+    };
+    let mut rows = Vec::new();
+    for (k, v) in map {
+        for item in v {
+            let (hash, key) = match (&item.hash, &item.key) {
+                (Some(hash), Some(key)) => (resolve(hash), resolve(key)),
+                (None, None) => continue,
+                _ => panic!("invalid oid_db format: both hash and key must be set for {}", item.oid),
+            };
+            let obsolete = item.status.as_deref() == Some("obsolete");
+            rows.push((obsolete, k.as_str(), &item.oid, hash, key));
+        }
+    }
+    rows.sort_by_key(|row| row.0);
+
+    writeln!(
+        out_file,
+        "pub(crate) static SIGNATURE_COMPONENTS: &[(Oid<'static>, Oid<'static>, Oid<'static>)] = &["
+    )?;
+    for (_, k, oid, (hash, hash_feature), (key, key_feature)) in rows {
+        let mut features = vec![k, hash_feature, key_feature];
+        features.sort_unstable();
+        features.dedup();
+        let features: Vec<_> = features.iter().map(|f| format!("feature = \"{}\"", f)).collect();
+        writeln!(out_file, "    #[cfg(all({}))]", features.join(", "))?;
+        writeln!(
+            out_file,
+            "    (asn1_rs::oid!({}), asn1_rs::oid!({}), asn1_rs::oid!({})),",
+            oid, hash, key
+        )?;
+    }
+    writeln!(out_file, "];")?;
+    Ok(())
+}
+
+// Name of the static table for a feature
+fn static_table_name(feature: &str) -> String {
+    format!("STATIC_TABLE_{}", feature.to_uppercase())
+}
+
+// Write the table of expected `AlgorithmIdentifier` parameters
+fn generate_parameter_table<W: Write>(map: &LoadedMap, out_file: &mut W) -> io::Result<()> {
+    writeln!(
+        out_file,
+        "pub(crate) static PARAMETER_EXPECTATIONS: &[(Oid<'static>, crate::ParameterExpectation)] = &["
+    )?;
+    for (k, v) in map {
+        for item in v {
+            if let Some(params) = &item.params {
+                writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
+                writeln!(
+                    out_file,
+                    "    (asn1_rs::oid!({}), {}),",
+                    item.oid,
+                    parameter_expectation_code(params).expect("invalid oid_db format: invalid parameters")
+                )?;
+            }
+        }
+    }
+    writeln!(out_file, "];")?;
+    Ok(())
+}
+
+// Write the table of hash algorithm properties
+fn generate_hash_table<W: Write>(map: &LoadedMap, out_file: &mut W) -> io::Result<()> {
+    writeln!(
+        out_file,
+        "pub(crate) static HASH_ALGORITHMS: &[(Oid<'static>, crate::HashAlgorithmInfo)] = &["
+    )?;
+    for (k, v) in map {
+        for item in v {
+            let (family, digest_size, block_size) = match (&item.family, item.digest_size, item.block_size) {
+                (Some(family), Some(digest_size), Some(block_size)) => (family, digest_size, block_size),
+                (None, None, None) => continue,
+                _ => panic!(
+                    "invalid oid_db format: family, digest-size and block-size must be set for {}",
+                    item.oid
+                ),
+            };
+            writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
+            writeln!(
+                out_file,
+                "    (asn1_rs::oid!({}), crate::HashAlgorithmInfo {{ family: crate::HashFamily::{}, digest_size: {}, block_size: {}, xof: {} }}),",
+                item.oid,
+                hash_family_variant(family).expect("invalid oid_db format: unknown hash family"),
+                digest_size,
+                block_size,
+                item.xof
+            )?;
+        }
+    }
+    writeln!(out_file, "];")?;
+    Ok(())
+}
+
+// Write the table of elliptic curve properties
+fn generate_curve_table<W: Write>(map: &LoadedMap, out_file: &mut W) -> io::Result<()> {
+    writeln!(out_file, "pub(crate) static EC_CURVES: &[(Oid<'static>, crate::EcCurveInfo)] = &[")?;
+    for (k, v) in map {
+        for item in v {
+            let (field_bits, form, security_bits) = match (item.field_bits, &item.curve_form, item.security_bits) {
+                (Some(field_bits), Some(form), Some(security_bits)) => (field_bits, form, security_bits),
+                (None, None, None) => continue,
+                _ => panic!(
+                    "invalid oid_db format: field-bits, curve-form and security-bits must be set for {}",
+                    item.oid
+                ),
+            };
+            let aliases: Vec<_> = item.aliases.iter().map(|alias| format!("{:?}", alias)).collect();
+            writeln!(out_file, r#"    #[cfg(feature = "{}")]"#, k)?;
+            writeln!(
+                out_file,
+                r#"    (asn1_rs::oid!({}), crate::EcCurveInfo {{ name: {:?}, aliases: &[{}], field_bits: {}, form: crate::CurveForm::{}, security_bits: {} }}),"#,
+                item.oid,
+                item.sn,
+                aliases.join(", "),
+                field_bits,
+                curve_form_variant(form).expect("invalid oid_db format: unknown curve form"),
+                security_bits
+            )?;
+        }
+    }
+    writeln!(out_file, "];")?;
     Ok(())
 }
+
+// Name of the `CurveForm` variant for a curve-form attribute
+fn curve_form_variant(form: &str) -> Option<&'static str> {
+    let variant = match form {
+        "weierstrass" => "ShortWeierstrass",
+        "edwards" => "Edwards",
+        "montgomery" => "Montgomery",
+        _ => return None,
+    };
+    Some(variant)
+}
+
+// Name of the `HashFamily` variant for a family attribute
+fn hash_family_variant(family: &str) -> Option<&'static str> {
+    let variant = match family {
+        "md" => "Md",
+        "sha1" => "Sha1",
+        "sha2" => "Sha2",
+        "sha3" => "Sha3",
+        "gost" => "Gost",
+        "ripemd" => "Ripemd",
+        _ => return None,
+    };
+    Some(variant)
+}
+
+// Code of the `ParameterExpectation` for a params attribute
+fn parameter_expectation_code(params: &str) -> Option<String> {
+    let variant = match params {
+        "absent" => "Absent",
+        "null" => "Null",
+        "absent-or-null" => "EitherAbsentOrNull",
+        _ => {
+            let (variant, tag) = params.split_once(':')?;
+            let variant = match variant {
+                "required" => "Required",
+                "optional" => "Optional",
+                _ => return None,
+            };
+            let tag = match tag {
+                "sequence" => "Sequence",
+                "oid" => "Oid",
+                "octet-string" => "OctetString",
+                "integer" => "Integer",
+                _ => return None,
+            };
+            return Some(format!("crate::ParameterExpectation::{}(asn1_rs::Tag::{})", variant, tag));
+        }
+    };
+    Some(format!("crate::ParameterExpectation::{}", variant))
+}
+
+// Name of the `OidKind` variant for a kind attribute
+fn kind_variant(kind: &str) -> Option<&'static str> {
+    let variant = match kind {
+        "signature" => "SignatureAlgorithm",
+        "hash" => "HashAlgorithm",
+        "public-key" => "PublicKeyAlgorithm",
+        "curve" => "EllipticCurve",
+        "encryption" => "EncryptionAlgorithm",
+        "kdf" => "KeyDerivation",
+        "extension" => "X509Extension",
+        "name-attribute" => "NameAttribute",
+        "attribute" => "Attribute",
+        "content-type" => "ContentType",
+        "access-method" => "AccessMethod",
+        "key-purpose" => "KeyPurpose",
+        "arc" => "Arc",
+        "other" => "Other",
+        _ => return None,
+    };
+    Some(variant)
+}
+
+// Name of the `OidStatus` variant for a status attribute
+fn status_variant(status: &str) -> Option<&'static str> {
+    let variant = match status {
+        "current" => "Current",
+        "deprecated" => "Deprecated",
+        "obsolete" => "Obsolete",
+        _ => return None,
+    };
+    Some(variant)
+}
+
+// Code for a list of static strings, as elements of a `[Cow<'static, str>]`
+fn static_cow_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("alloc::borrow::Cow::Borrowed({:?})", item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Encode a dotted OID (ex: 2.5.4.3) to the content octets of its DER encoding
+fn encode_oid_der(oid: &str) -> Vec<u8> {
+    let arcs: Vec<u64> = oid
+        .split('.')
+        .map(|arc| arc.parse().expect("invalid oid_db format: invalid OID arc"))
+        .collect();
+    assert!(arcs.len() >= 2, "invalid oid_db format: OID must have at least 2 arcs");
+    let first = first_arcs(arcs[0], arcs[1]).expect("invalid oid_db format: second arc is too large");
+    let mut der = Vec::new();
+    encode_arc(&mut der, first);
+    for &arc in &arcs[2..] {
+        encode_arc(&mut der, arc);
+    }
+    der
+}
+
+// Value of the first encoded arc, if it does not overflow
+fn first_arcs(first: u64, second: u64) -> Option<u64> {
+    first.checked_mul(40)?.checked_add(second)
+}
+
+// Encode an arc in base 128, with the high bit set on all bytes except the last one
+fn encode_arc(der: &mut Vec<u8>, arc: u64) {
+    let bits = 64 - arc.leading_zeros() as usize;
+    let len = std::cmp::max(1, (bits + 6) / 7);
+    for i in (0..len).rev() {
+        let b = ((arc >> (7 * i)) & 0x7f) as u8;
+        der.push(if i > 0 { b | 0x80 } else { b });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("oid-registry-{}-{}.txt", name, std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let content = "# comment\n\
+                       x509\tOID_A\t2.5.4.3\tcommonName\tCommon Name\tkind=name-attribute\n\
+                       x509\tOID_B\t2.5.4.x\tbad\tBad OID\n\
+                       x509\tOID_C\t2.5.4.4\n\
+                       x509\tOID_D\t2.5.4.5\tsn\tUnknown attribute\tcolor=blue\n\
+                       x509\tOID_E\t2.5.4.6\tsn2\tBad kind\tkind=nope\n\
+                       x509\tOID_F\t2.5.4.7\tsn3\tBad strength\tstrength=-1\n\
+                       x509\tOID_G\t2.5.4.8\tsn4\tNo value\tstrength\n\
+                       x509\tOID_H\t2.5.4.9\tsn5\tOK\n";
+        let path = write_temp_file("load-errors", content);
+
+        // strict mode stops at the first error
+        let e = load_file(&path).expect_err("strict loading should fail");
+        assert_eq!(e.line(), Some(3));
+        assert_eq!(e.path(), Some(path.as_path()));
+        match &e {
+            LoadError::InvalidField { column, field, text, .. } => {
+                assert_eq!(*column, 3);
+                assert_eq!(field, "oid");
+                assert_eq!(text, "2.5.4.x");
+            }
+            _ => panic!("unexpected error {:?}", e),
+        }
+        assert!(e.to_string().ends_with(":3:3: invalid value for 'oid': '2.5.4.x'"));
+
+        // lenient mode skips invalid lines
+        let (map, errors) = load_file_lenient(&path).expect("lenient loading should not fail");
+        let names: Vec<_> = map["x509"].iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["OID_A", "OID_H"]);
+        let lines: Vec<_> = errors.iter().map(|e| e.line().unwrap()).collect();
+        assert_eq!(lines, [3, 4, 5, 6, 7, 8]);
+        assert!(matches!(
+            errors[1],
+            LoadError::MissingField {
+                column: 4,
+                field: "short name",
+                ..
+            }
+        ));
+        assert!(matches!(&errors[2], LoadError::UnknownAttribute { column: 6, text, .. } if text == "color=blue"));
+        assert!(matches!(&errors[3], LoadError::InvalidField { field, text, .. } if field == "kind" && text == "nope"));
+        assert!(matches!(&errors[4], LoadError::InvalidField { field, .. } if field == "strength"));
+        assert!(matches!(&errors[5], LoadError::InvalidField { field, .. } if field == "attribute"));
+
+        std::fs::remove_file(&path).unwrap();
+
+        // I/O errors are fatal, even in lenient mode
+        let e = load_file_lenient(&path).expect_err("missing file");
+        assert!(matches!(e, LoadError::Io { .. }));
+        assert_eq!(e.line(), None);
+    }
+
+    fn entry(name: &str, oid: &str, sn: &str) -> LoadedEntry {
+        LoadedEntry {
+            name: name.to_string(),
+            oid: oid.to_string(),
+            sn: sn.to_string(),
+            description: "description".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut map = LoadedMap::new();
+        map.insert(
+            "x509".to_string(),
+            vec![
+                entry("OID_A", "2.5.4.3", "commonName"),
+                entry("OID_B ", "2.5.4.4", "surname"),
+                entry("OID_C", "2.5.04.5", "c"),
+                entry("1OID", "3.1", "d"),
+                entry("Oid_E", "1.40", "e"),
+                entry("OID_F", "2.18446744073709551600", "f2"),
+                entry("\"\"", "2.5.4.6", "f"),
+                entry("\"\"", "2.5.4.7", "g"),
+            ],
+        );
+        let mut signature = entry("OID_SIG", "1.2.3", "sig");
+        signature.hash = Some("sha1".to_string());
+        signature.key = Some("rsa".to_string());
+        let mut hash = entry("OID_HASH", "1.2.4", "sha1");
+        hash.family = Some("sha1".to_string());
+        hash.aliases.push("sha-1".to_string());
+        // components can be referenced by alias
+        let mut signature2 = entry("OID_SIG2", "1.2.5", "sig2");
+        signature2.hash = Some("sha-1".to_string());
+        signature2.key = Some("sig".to_string());
+        map.insert(
+            "Pkcs1".to_string(),
+            vec![
+                entry("OID_A", "2.5.4.3", "commonName"),
+                entry("OID_AA", "2.5.4.8", "surname"),
+                signature,
+                hash,
+                signature2,
+            ],
+        );
+
+        let diagnostics = validate(&map, None);
+        let messages: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.oid.as_deref(), d.message.as_str(), d.is_fatal()))
+            .collect();
+        let expected = [
+            (None, "feature name 'Pkcs1' should be a lowercase identifier", true),
+            (Some("2.5.4.3"), "duplicate OID, already declared in feature Pkcs1", true),
+            (Some("2.5.4.3"), "duplicate constant name 'OID_A', already used by 2.5.4.3", true),
+            (Some("2.5.4.4"), "name \"OID_B \" has leading or trailing whitespace", true),
+            (Some("2.5.4.4"), "duplicate short name 'surname', already used by 2.5.4.8", false),
+            (Some("2.5.04.5"), "invalid OID: arc '04' has leading zeros", true),
+            (Some("3.1"), "invalid OID: first arc must be 0, 1 or 2, found 3", true),
+            (Some("3.1"), "constant name '1OID' is not a valid identifier", true),
+            (
+                Some("1.40"),
+                "invalid OID: second arc must be lower than 40 below arc 1, found 40",
+                true,
+            ),
+            (Some("1.40"), "constant name 'Oid_E' should be in upper case", false),
+            (
+                Some("2.18446744073709551600"),
+                "invalid OID: second arc '18446744073709551600' is too large",
+                true,
+            ),
+            (Some("1.2.3"), "unknown short name 'rsa' in signature components", true),
+            (Some("1.2.4"), "family, digest-size and block-size must be set together", true),
+        ];
+        assert_eq!(messages, expected);
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "error: 2.5.4.3 (feature x509): duplicate OID, already declared in feature Pkcs1"
+        );
+    }
+
+    #[test]
+    fn test_write_map() {
+        let mut map = load_file(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/oid_db.txt")).unwrap();
+        let mut output = Vec::new();
+        write_map(&map, &mut output).unwrap();
+        let loaded = load_reader(&output[..]).unwrap();
+        for entries in map.values_mut() {
+            entries.sort_by_key(|entry| oid_sort_key(&entry.oid));
+        }
+        assert_eq!(loaded, map);
+
+        // the output is the assets file, without comments and empty lines
+        let assets = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/oid_db.txt")).unwrap();
+        let assets: String = assets
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        assert_eq!(std::str::from_utf8(&output).unwrap(), assets);
+
+        // the output is deterministic
+        let mut output2 = Vec::new();
+        write_map(&loaded, &mut output2).unwrap();
+        assert_eq!(output, output2);
+
+        // entries without attributes are written without attributes
+        let mut bare = LoadedMap::new();
+        bare.insert("f".to_string(), vec![entry("\"\"", "1.2.3", "a")]);
+        let mut bare_output = Vec::new();
+        write_map(&bare, &mut bare_output).unwrap();
+        assert_eq!(bare_output, b"f\t\"\"\t1.2.3\ta\tdescription\n");
+        assert_eq!(load_reader(&bare_output[..]).unwrap(), bare);
+
+        // entries are sorted by arcs, and features use the asset names
+        let text = String::from_utf8(output).unwrap();
+        let x509: Vec<_> = text.lines().filter(|line| line.starts_with("x509\t")).collect();
+        let keys: Vec<_> = x509.iter().map(|line| oid_sort_key(line.split('\t').nth(2).unwrap())).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(text.lines().any(|line| line.starts_with("nist-algs\t")));
+
+        let mut map = LoadedMap::new();
+        map.insert("x509".to_string(), vec![entry("OID_A", "2.5.4.3", "")]);
+        let e = write_map(&map, io::sink()).expect_err("empty short name");
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_signature_components_alias() {
+        let mut hash = entry("OID_HASH", "1.2.4", "sha1");
+        hash.aliases.push("hh".to_string());
+        let mut signature = entry("OID_SIG", "1.2.3", "sig");
+        signature.hash = Some("hh".to_string());
+        signature.key = Some("sig".to_string());
+        let mut map = LoadedMap::new();
+        map.insert("x509".to_string(), vec![hash, signature]);
+        assert!(validate(&map, None).is_empty());
+
+        // feature names must be declared
+        assert!(validate(&map, Some(&["x509", "pkcs1"])).is_empty());
+        let diagnostics = validate(&map, Some(&["x590"]));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].to_string(), "error: feature x509: feature 'x509' is not declared");
+
+        let path = std::env::temp_dir().join(format!("oid-registry-alias-tables-{}.rs", std::process::id()));
+        generate_tables(&map, &path).unwrap();
+        let tables = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(
+            tables.contains("    #[cfg(all(feature = \"x509\"))]\n    (asn1_rs::oid!(1.2.3), asn1_rs::oid!(1.2.4), asn1_rs::oid!(1.2.3)),")
+        );
+
+        // rows are only compiled if the features of all components are enabled
+        let mut signature = entry("OID_SIG2", "1.2.6", "sig2");
+        signature.hash = Some("sha1".to_string());
+        signature.key = Some("sig".to_string());
+        map.insert("pkcs1".to_string(), vec![signature]);
+        generate_tables(&map, &path).unwrap();
+        let tables = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(tables.contains(
+            "    #[cfg(all(feature = \"pkcs1\", feature = \"x509\"))]\n    (asn1_rs::oid!(1.2.6), asn1_rs::oid!(1.2.4), asn1_rs::oid!(1.2.3)),"
+        ));
+        map.remove("pkcs1");
+
+        // components cannot be referenced by a duplicate short name
+        map.insert("pkcs1".to_string(), vec![entry("OID_HH", "1.2.5", "hh")]);
+        let diagnostics: Vec<_> = validate(&map, None).iter().map(Diagnostic::to_string).collect();
+        assert_eq!(
+            diagnostics,
+            [
+                "warning: 1.2.4 (feature x509): duplicate short name 'hh', already used by 1.2.5",
+                "error: 1.2.3 (feature x509): ambiguous short name 'hh' in signature components",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_map_all_chars() {
+        // descriptions covering all chars of the BMP, and samples of the other planes
+        let chars = (0..0x1_0000).chain((0x1_0000..0x11_0000).step_by(97)).filter_map(char::from_u32);
+        let chars: Vec<char> = chars.filter(|c| !matches!(c, '\t' | '\n' | '\r')).collect();
+        let entries = chars
+            .chunks(256)
+            .enumerate()
+            .map(|(idx, chunk)| LoadedEntry {
+                description: chunk.iter().collect(),
+                ..entry("\"\"", &format!("1.2.3.{}", idx), &format!("sn{}", idx))
+            })
+            .collect();
+        let mut map = LoadedMap::new();
+        map.insert("x509".to_string(), entries);
+
+        let mut data = Vec::new();
+        write_map(&map, &mut data).unwrap();
+        assert_eq!(load_reader(&data[..]).unwrap(), map);
+    }
+
+    // Code generated by the build script from `assets/tests/escaping.txt`
+    #[cfg(feature = "registry")]
+    #[rustfmt::skip::macros(oid)]
+    mod escaping {
+        #![allow(dead_code, unreachable_pub)]
+        use crate::{OidEntry, OidKind, StaticTable};
+        use asn1_rs::{oid, Oid};
+        use std::str::FromStr;
+
+        // Stand-in for the registry, since the generated `with_all_features` is already defined
+        #[derive(Default)]
+        struct OidRegistry<'a>(crate::OidRegistry<'a>);
+
+        impl<'a> OidRegistry<'a> {
+            fn insert(&mut self, oid: Oid<'a>, entry: OidEntry) {
+                self.0.insert(oid, entry);
+            }
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/escaping_db.rs"));
+        include!(concat!(env!("OUT_DIR"), "/escaping_tables.rs"));
+
+        #[test]
+        fn test_escaping_round_trip() {
+            let map = super::load_file(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/tests/escaping.txt")).unwrap();
+            let loaded = &map["registry"];
+            // `generate_file` output
+            let registry = OidRegistry::default().with_all_features().0;
+            assert_eq!(registry.len(), loaded.len());
+            // `generate_tables` output
+            assert_eq!(STATIC_TABLE_REGISTRY.entries.len(), loaded.len());
+            for item in loaded {
+                let oid = Oid::from_str(&item.oid).unwrap();
+                let entry = registry.get(&oid).expect("entry not generated");
+                assert_eq!(entry.sn(), item.sn);
+                assert_eq!(entry.description(), item.description);
+                assert_eq!(entry.long_name(), item.long_name.as_deref());
+                assert!(entry.aliases().eq(item.aliases.iter().map(String::as_str)));
+                assert!(entry.references().eq(item.references.iter().map(String::as_str)));
+                let entries = STATIC_TABLE_REGISTRY.entries;
+                let idx = entries.iter().position(|(o, _)| *o == oid).expect("entry not generated");
+                assert_eq!(&entries[idx].1, entry);
+                for name in std::iter::once(&item.sn).chain(&item.aliases) {
+                    assert!(STATIC_TABLE_REGISTRY.by_sn.contains(&(name.as_str(), idx as u16)));
+                    assert!(registry.iter_by_sn(name).any(|(o, _)| *o == oid));
+                }
+            }
+            assert!(loaded.iter().any(|item| item.description.contains('\u{1b}')));
+
+            assert_eq!(OID_ESCAPING_QUOTES, oid!(1.2.3.1));
+            assert_eq!(OID_ESCAPING_EMPTY, oid!(1.2.3.7));
+            let (_, curve) = &EC_CURVES[0];
+            assert_eq!(curve.name(), "curve\"\\name");
+            assert_eq!(curve.aliases(), ["al\"ias"]);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Reading of the features declared in `Cargo.toml`
+//
+// This file is included by the build script, and only compiled in the crate for tests.
+
+// Names of the features declared in the `[features]` table of a manifest
+//
+// This is not a full TOML parser, but it handles what can appear in a `[features]` table:
+// comments, quoted keys, and arrays (or inline tables) spanning several lines.
+fn declared_features(manifest: &str) -> Vec<String> {
+    let mut features = Vec::new();
+    let mut in_features = false;
+    // nesting level of arrays and inline tables
+    let mut depth = 0;
+    for line in manifest.lines() {
+        let (len, nesting) = scan_toml_line(line);
+        let line = line[..len].trim();
+        if depth == 0 {
+            if line.starts_with('[') {
+                let header: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+                in_features = header == "[features]";
+                continue;
+            }
+            if in_features {
+                if let Some(key) = toml_key(line) {
+                    features.push(key);
+                }
+            }
+        }
+        depth += nesting;
+    }
+    features
+}
+
+// Scan a line, and return its length without the comment, and the change of the nesting level of
+// arrays and inline tables
+fn scan_toml_line(line: &str) -> (usize, i32) {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut nesting = 0;
+    for (idx, c) in line.char_indices() {
+        match quote {
+            Some(q) => {
+                if c == q && !escaped {
+                    quote = None;
+                }
+                // only basic strings have escapes
+                escaped = q == '"' && c == '\\' && !escaped;
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => nesting += 1,
+                ']' | '}' => nesting -= 1,
+                '#' => return (idx, nesting),
+                _ => (),
+            },
+        }
+    }
+    (line.len(), nesting)
+}
+
+// Unquoted key of a `key = value` line
+fn toml_key(line: &str) -> Option<String> {
+    match line.chars().next()? {
+        q @ ('"' | '\'') => {
+            let end = line[1..].find(q)? + 1;
+            line[end + 1..].trim_start().strip_prefix('=')?;
+            Some(line[1..end].to_string())
+        }
+        _ => line.split_once('=').map(|(key, _)| key.trim().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_features() {
+        let manifest = r#"
+[package]
+name = "test"
+features = ["not-a-feature"]
+
+[features] # the features
+default = ["registry", "std"]
+registry = []
+std = [
+  "asn1-rs/std", # comment with a = sign
+  "dep:once_cell",
+  # not_a_feature = []
+]
+"quoted-key" = []
+'literal' = []
+"with # hash" = ["a#b"]
+inline = { not_a_key = 1 }
+nested = [
+  "]",
+  '[',
+]
+after = []
+
+[ dependencies ]
+asn1-rs = { version = "0.6", default-features = false }
+"#;
+        let features = declared_features(manifest);
+        assert_eq!(
+            features,
+            [
+                "default",
+                "registry",
+                "std",
+                "quoted-key",
+                "literal",
+                "with # hash",
+                "inline",
+                "nested",
+                "after"
+            ]
+        );
+
+        // the features of this crate
+        let manifest = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap();
+        let features = declared_features(&manifest);
+        assert!(features.iter().any(|f| f == "x509"));
+        assert!(!features.iter().any(|f| f == "asn1-rs"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Structured metadata attached to registry entries
+
+use core::fmt;
+
+/// Status of an OID, as recorded in the registry
+///
+/// The status describes the OID itself, not the strength of the algorithm it may identify: an OID
+/// is *obsolete* when it was replaced by another identifier (for ex. the first X.509 extension
+/// OIDs), and *deprecated* when its use is discouraged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OidStatus {
+    /// The OID is in use
+    #[default]
+    Current,
+    /// The use of this OID is discouraged
+    Deprecated,
+    /// The OID was superseded, and should not be used anymore
+    Obsolete,
+}
+
+impl OidStatus {
+    /// Returns `true` if the OID is deprecated or obsolete
+    #[inline]
+    pub const fn is_deprecated(self) -> bool {
+        !matches!(self, OidStatus::Current)
+    }
+
+    /// Get the name of this status, as used in the assets files
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            OidStatus::Current => "current",
+            OidStatus::Deprecated => "deprecated",
+            OidStatus::Obsolete => "obsolete",
+        }
+    }
+
+    /// Get a status from its name, as used in the assets files
+    pub fn from_name(name: &str) -> Option<Self> {
+        [OidStatus::Current, OidStatus::Deprecated, OidStatus::Obsolete]
+            .iter()
+            .copied()
+            .find(|status| status.as_str() == name)
+    }
+}
+
+impl fmt::Display for OidStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Category of an OID: what kind of object it identifies
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OidKind {
+    /// Signature algorithm (for ex. `sha256WithRSAEncryption`)
+    SignatureAlgorithm,
+    /// Hash algorithm (for ex. `sha256`)
+    HashAlgorithm,
+    /// Public key type (for ex. `rsaEncryption`, `id-ecPublicKey`)
+    PublicKeyAlgorithm,
+    /// Named elliptic curve (for ex. `prime256v1`)
+    EllipticCurve,
+    /// Encryption algorithm, including password-based encryption schemes
+    EncryptionAlgorithm,
+    /// Key derivation or key agreement scheme
+    KeyDerivation,
+    /// Certificate or CRL extension
+    X509Extension,
+    /// Attribute type of a distinguished name (for ex. `commonName`)
+    NameAttribute,
+    /// Other attribute type, for ex. CMS or PKCS #9 attributes (for ex. `signing-time`)
+    Attribute,
+    /// CMS (PKCS #7) content type
+    ContentType,
+    /// Access method, used in authority or subject information access
+    AccessMethod,
+    /// Key purpose, used in extended key usage
+    KeyPurpose,
+    /// Arc of the OID tree, used as a prefix for other OIDs (for ex. `pkcs-12`)
+    Arc,
+    /// Unclassified OID
+    #[default]
+    Other,
+}
+
+impl OidKind {
+    /// Get the name of this kind, as used in the assets files
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            OidKind::SignatureAlgorithm => "signature",
+            OidKind::HashAlgorithm => "hash",
+            OidKind::PublicKeyAlgorithm => "public-key",
+            OidKind::EllipticCurve => "curve",
+            OidKind::EncryptionAlgorithm => "encryption",
+            OidKind::KeyDerivation => "kdf",
+            OidKind::X509Extension => "extension",
+            OidKind::NameAttribute => "name-attribute",
+            OidKind::Attribute => "attribute",
+            OidKind::ContentType => "content-type",
+            OidKind::AccessMethod => "access-method",
+            OidKind::KeyPurpose => "key-purpose",
+            OidKind::Arc => "arc",
+            OidKind::Other => "other",
+        }
+    }
+
+    /// Get a kind from its name, as used in the assets files
+    pub fn from_name(name: &str) -> Option<Self> {
+        const KINDS: [OidKind; 14] = [
+            OidKind::SignatureAlgorithm,
+            OidKind::HashAlgorithm,
+            OidKind::PublicKeyAlgorithm,
+            OidKind::EllipticCurve,
+            OidKind::EncryptionAlgorithm,
+            OidKind::KeyDerivation,
+            OidKind::X509Extension,
+            OidKind::NameAttribute,
+            OidKind::Attribute,
+            OidKind::ContentType,
+            OidKind::AccessMethod,
+            OidKind::KeyPurpose,
+            OidKind::Arc,
+            OidKind::Other,
+        ];
+        KINDS.iter().copied().find(|kind| kind.as_str() == name)
+    }
+}
+
+impl fmt::Display for OidKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
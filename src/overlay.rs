@@ -0,0 +1,149 @@
+//! Layered registry, shadowing a shared base registry
+
+use crate::{Oid, OidEntry, OidRegistry};
+use alloc::format;
+use alloc::string::String;
+
+/// A registry layered over a shared base registry
+///
+/// Entries are inserted in a local layer. Lookups first search the local layer, and fall through
+/// to the base registry, which is never modified nor copied. Local entries shadow the base
+/// entries with the same OID.
+///
+/// This allows sharing a large registry (for ex. with all crypto OIDs), while adding a few
+/// specific entries.
+///
+/// ```rust
+/// use asn1_rs::oid;
+/// use oid_registry::{OidRegistry, OidRegistryOverlay};
+///
+/// let mut base = OidRegistry::default();
+/// base.insert(oid!(1.2.3.4), ("shortName", "A description"));
+///
+/// let mut overlay = OidRegistryOverlay::new(&base);
+/// overlay.insert(oid!(1.2.3.4), ("localName", "A local description"));
+/// overlay.insert(oid!(1.2.3.5), ("otherName", "Another description"));
+///
+/// assert_eq!(overlay.get(&oid!(1.2.3.4)).map(|e| e.sn()), Some("localName"));
+/// assert_eq!(base.get(&oid!(1.2.3.4)).map(|e| e.sn()), Some("shortName"));
+/// assert_eq!(overlay.iter().count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct OidRegistryOverlay<'r, 'a> {
+    base: &'r OidRegistry<'a>,
+    local: OidRegistry<'a>,
+}
+
+impl<'r, 'a> OidRegistryOverlay<'r, 'a> {
+    /// Create a new overlay, with an empty local layer over `base`
+    pub fn new(base: &'r OidRegistry<'a>) -> Self {
+        OidRegistryOverlay {
+            base,
+            local: OidRegistry::default(),
+        }
+    }
+
+    /// Get the base registry
+    #[inline]
+    pub fn base(&self) -> &'r OidRegistry<'a> {
+        self.base
+    }
+
+    /// Get the local layer
+    #[inline]
+    pub fn local(&self) -> &OidRegistry<'a> {
+        &self.local
+    }
+
+    /// Get the local layer, to modify it
+    #[inline]
+    pub fn local_mut(&mut self) -> &mut OidRegistry<'a> {
+        &mut self.local
+    }
+
+    /// Insert a new entry in the local layer
+    ///
+    /// Returns the previous entry of the local layer, if any.
+    pub fn insert<E>(&mut self, oid: Oid<'a>, entry: E) -> Option<OidEntry>
+    where
+        E: Into<OidEntry>,
+    {
+        self.local.insert(oid, entry)
+    }
+
+    /// Returns a reference to the registry entry, if found for this OID.
+    ///
+    /// The local layer is searched first.
+    pub fn get(&self, oid: &Oid) -> Option<&OidEntry> {
+        self.local.get(oid).or_else(|| self.base.get(oid))
+    }
+
+    /// Returns `true` if the local layer or the base registry contains an entry for this OID
+    pub fn contains(&self, oid: &Oid) -> bool {
+        self.local.contains(oid) || self.base.contains(oid)
+    }
+
+    /// Return an Iterator over references to the `(Oid, OidEntry)` key/value pairs
+    ///
+    /// Entries of the local layer are returned first. Shadowed entries of the base registry are
+    /// not returned.
+    pub fn iter(&self) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        let local = &self.local;
+        let base = self.base.iter().filter(move |(oid, _)| !local.contains(oid));
+        self.local.iter().chain(base)
+    }
+
+    /// Return the `(Oid, OidEntry)` key/value pairs, matching a short name or an alias
+    ///
+    /// Entries of the local layer are returned first. Shadowed entries of the base registry are
+    /// not returned.
+    pub fn iter_by_sn<S: AsRef<str>>(&self, sn: S) -> impl Iterator<Item = (&Oid<'a>, &OidEntry)> {
+        let local = &self.local;
+        let base = self.base.iter_by_sn_str(sn.as_ref()).filter(move |(oid, _)| !local.contains(oid));
+        self.local.iter_by_sn_str(sn.as_ref()).chain(base)
+    }
+
+    /// Format a OID to a `String`, using the overlay to get the short name if present.
+    ///
+    /// See [`format_oid`](crate::format_oid).
+    pub fn format_oid(&self, oid: &Oid) -> String {
+        match self.get(oid) {
+            Some(entry) => format!("{} ({})", entry.sn(), oid),
+            None => format!("{}", oid),
+        }
+    }
+
+    /// Consume the overlay, and return the local layer
+    pub fn into_local(self) -> OidRegistry<'a> {
+        self.local
+    }
+}
+
+#[rustfmt::skip::macros(oid)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_overlay_shadowing() {
+        let mut base = OidRegistry::default();
+        base.insert(oid!(1.2.3.4), ("a", "A"));
+        base.insert(oid!(1.2.3.5), ("b", "B"));
+
+        let mut overlay = OidRegistryOverlay::new(&base);
+        overlay.insert(oid!(1.2.3.5), ("c", "C"));
+        overlay.insert(oid!(1.2.3.6), ("a", "Local A"));
+
+        assert_eq!(overlay.iter().count(), 3);
+        assert_eq!(overlay.iter_by_sn("a").count(), 2);
+        // entry "b" is shadowed by "c"
+        assert_eq!(overlay.iter_by_sn("b").count(), 0);
+        assert_eq!(overlay.iter_by_sn("c").count(), 1);
+        assert_eq!(overlay.format_oid(&oid!(1.2.3.4)), "a (1.2.3.4)");
+        assert_eq!(overlay.format_oid(&oid!(1.2.3.5)), "c (1.2.3.5)");
+        assert_eq!(overlay.format_oid(&oid!(1.2.3.7)), "1.2.3.7");
+        assert!(overlay.contains(&oid!(1.2.3.4)));
+        assert_eq!(overlay.into_local().len(), 2);
+    }
+}
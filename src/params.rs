@@ -0,0 +1,165 @@
+//! Expected parameters of algorithm identifiers
+
+use crate::static_registry::PARAMETER_EXPECTATIONS;
+use crate::OidRegistryError;
+use asn1_rs::{Any, Class, Oid, Tag};
+use core::fmt;
+
+/// Expected form of the `parameters` field of an `AlgorithmIdentifier`
+///
+/// ```asn1
+/// AlgorithmIdentifier  ::=  SEQUENCE  {
+///      algorithm               OBJECT IDENTIFIER,
+///      parameters              ANY DEFINED BY algorithm OPTIONAL  }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterExpectation {
+    /// The parameters must be absent (for ex. ECDSA signatures, Ed25519)
+    Absent,
+    /// The parameters must be an ASN.1 `NULL` (for ex. RSA PKCS #1 v1.5 signatures)
+    Null,
+    /// The parameters must be present, with the given universal tag (for ex. the named curve OID
+    /// of `id-ecPublicKey`, or the `SEQUENCE` of RSASSA-PSS)
+    Required(Tag),
+    /// The parameters can be absent or `NULL` (for ex. hash algorithms)
+    EitherAbsentOrNull,
+    /// The parameters can be absent, or present with the given universal tag (for ex. the
+    /// `Dss-Parms` of `id-dsa`, which may be inherited from the issuer)
+    Optional(Tag),
+}
+
+impl ParameterExpectation {
+    /// Returns `true` if the `parameters` of an `AlgorithmIdentifier` have the expected form
+    ///
+    /// Only the form of the parameters is checked, not their content.
+    pub fn matches(&self, parameters: Option<&Any>) -> bool {
+        match (self, parameters) {
+            (ParameterExpectation::Absent, params) => params.is_none(),
+            (ParameterExpectation::Null, Some(params)) => is_null(params),
+            (ParameterExpectation::Required(tag), Some(params)) => params.class() == Class::Universal && params.tag() == *tag,
+            (ParameterExpectation::EitherAbsentOrNull, params) => params.map_or(true, is_null),
+            (ParameterExpectation::Optional(tag), params) => {
+                params.map_or(true, |params| params.class() == Class::Universal && params.tag() == *tag)
+            }
+            (_, None) => false,
+        }
+    }
+}
+
+impl fmt::Display for ParameterExpectation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParameterExpectation::Absent => f.write_str("absent"),
+            ParameterExpectation::Null => f.write_str("NULL"),
+            ParameterExpectation::Required(tag) => write!(f, "{}", tag),
+            ParameterExpectation::EitherAbsentOrNull => f.write_str("absent or NULL"),
+            ParameterExpectation::Optional(tag) => write!(f, "absent or {}", tag),
+        }
+    }
+}
+
+fn is_null(any: &Any) -> bool {
+    any.class() == Class::Universal && any.tag() == Tag::Null && any.data.is_empty()
+}
+
+/// Get the expected form of the `AlgorithmIdentifier` parameters for an algorithm
+///
+/// Returns `None` if the algorithm is unknown, or if its feature is not enabled.
+pub fn parameter_expectation(algorithm: &Oid) -> Option<ParameterExpectation> {
+    PARAMETER_EXPECTATIONS
+        .iter()
+        .find(|(oid, _)| oid == algorithm)
+        .map(|(_, expectation)| *expectation)
+}
+
+/// Check the `parameters` of an `AlgorithmIdentifier` against the expected form for the algorithm
+///
+/// Returns an `UnknownAlgorithm` error if no expectation is known for this algorithm, and an
+/// `InvalidParameters` error if the parameters do not have the expected form.
+///
+/// ```rust
+/// use oid_registry::validate_parameters;
+///
+/// # #[cfg(feature = "x962")] {
+/// // ECDSA signatures must not have parameters
+/// assert!(validate_parameters(&oid_registry::OID_SIG_ECDSA_WITH_SHA256, None).is_ok());
+/// # }
+/// ```
+pub fn validate_parameters(algorithm: &Oid, parameters: Option<&Any>) -> Result<(), OidRegistryError> {
+    let expected = match parameter_expectation(algorithm) {
+        Some(expected) => expected,
+        None => return Err(OidRegistryError::UnknownAlgorithm { oid: algorithm.to_owned() }),
+    };
+    if expected.matches(parameters) {
+        Ok(())
+    } else {
+        Err(OidRegistryError::InvalidParameters {
+            oid: algorithm.to_owned(),
+            expected,
+            found: parameters.map(|params| params.tag()),
+        })
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::{oid, FromDer};
+
+    #[test]
+    fn test_parameter_expectation() {
+        let null = Any::from_der(&[0x05, 0x00]).unwrap().1;
+        let oid_param = Any::from_der(&[0x06, 0x03, 0x2a, 0x03, 0x04]).unwrap().1;
+        let seq = Any::from_der(&[0x30, 0x00]).unwrap().1;
+
+        assert!(ParameterExpectation::Absent.matches(None));
+        assert!(!ParameterExpectation::Absent.matches(Some(&null)));
+        assert!(ParameterExpectation::Null.matches(Some(&null)));
+        assert!(!ParameterExpectation::Null.matches(None));
+        assert!(!ParameterExpectation::Null.matches(Some(&seq)));
+        assert!(ParameterExpectation::Required(Tag::Oid).matches(Some(&oid_param)));
+        assert!(!ParameterExpectation::Required(Tag::Oid).matches(Some(&seq)));
+        assert!(!ParameterExpectation::Required(Tag::Oid).matches(None));
+        assert!(ParameterExpectation::EitherAbsentOrNull.matches(None));
+        assert!(ParameterExpectation::EitherAbsentOrNull.matches(Some(&null)));
+        assert!(!ParameterExpectation::EitherAbsentOrNull.matches(Some(&seq)));
+        assert!(ParameterExpectation::Optional(Tag::Sequence).matches(None));
+        assert!(ParameterExpectation::Optional(Tag::Sequence).matches(Some(&seq)));
+        assert!(!ParameterExpectation::Optional(Tag::Sequence).matches(Some(&null)));
+
+        assert_eq!(
+            validate_parameters(&oid!(1.2.3.4), None),
+            Err(OidRegistryError::UnknownAlgorithm { oid: oid!(1.2.3.4) })
+        );
+
+        #[cfg(all(feature = "pkcs1", feature = "x962", feature = "x509"))]
+        {
+            use crate::*;
+
+            assert!(validate_parameters(&OID_PKCS1_SHA256WITHRSA, Some(&null)).is_ok());
+            assert_eq!(
+                validate_parameters(&OID_PKCS1_SHA256WITHRSA, None),
+                Err(OidRegistryError::InvalidParameters {
+                    oid: OID_PKCS1_SHA256WITHRSA,
+                    expected: ParameterExpectation::Null,
+                    found: None,
+                })
+            );
+            assert!(validate_parameters(&OID_SIG_ECDSA_WITH_SHA384, Some(&null)).is_err());
+            assert!(validate_parameters(&OID_SIG_ED25519, None).is_ok());
+            assert!(validate_parameters(&OID_KEY_TYPE_EC_PUBLIC_KEY, Some(&oid_param)).is_ok());
+            assert!(validate_parameters(&OID_PKCS1_RSASSAPSS, Some(&seq)).is_ok());
+            assert!(validate_parameters(&OID_PKCS1_RSASSAPSS, None).is_err());
+            assert!(validate_parameters(&OID_KEY_TYPE_DSA, None).is_ok());
+            assert!(validate_parameters(&OID_KEY_TYPE_DSA, Some(&seq)).is_ok());
+            assert!(validate_parameters(&OID_KEY_TYPE_DSA, Some(&null)).is_err());
+
+            // every signature, hash and public key algorithm has an expectation
+            let kinds = [OidKind::SignatureAlgorithm, OidKind::HashAlgorithm, OidKind::PublicKeyAlgorithm];
+            for (oid, entry) in kinds.iter().flat_map(|kind| StaticOidRegistry::new().iter_by_kind(*kind)) {
+                assert!(parameter_expectation(oid).is_some(), "no expectation for {}", entry.sn());
+            }
+        }
+    }
+}
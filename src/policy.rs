@@ -0,0 +1,352 @@
+//! Security policies, classifying algorithms as allowed, deprecated or forbidden
+
+use crate::static_registry::SECURITY_STRENGTHS;
+use crate::{ec_curve_info, signature_components, EcCurveInfo, OidEntry, OidKind, OidRegistry};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec::Vec;
+use asn1_rs::Oid;
+use core::fmt;
+
+/// Get the security strength of an algorithm, in bits
+///
+/// The security strength is given as specified by NIST SP 800-57 when applicable. `0` means that
+/// the algorithm is broken.
+///
+/// For signature algorithms, this is the strength of the hash algorithm (collision resistance),
+/// bounded by the security level of the key type when it is fixed by the algorithm (for ex.
+/// Ed25519). It is only an upper bound of the strength of a signature: when the key size or the
+/// curve is given by the key (for ex. RSA or ECDSA), the strength of the key is not known, so
+/// `ecdsa-with-SHA512` is rated 256 bits even when used with a P-256 key. The size of the key
+/// must be checked separately.
+///
+/// Returns `None` if the algorithm is unknown (or its feature is not enabled), or if the strength
+/// depends on the key or the parameters (for ex. `rsaEncryption` or RSASSA-PSS).
+pub fn security_strength(oid: &Oid) -> Option<usize> {
+    let strength = SECURITY_STRENGTHS.iter().find(|(o, _)| o == oid).map(|(_, strength)| *strength)?;
+    let key_strength = signature_components(oid)
+        .and_then(|(_, key)| ec_curve_info(key))
+        .map(EcCurveInfo::security_bits);
+    Some(key_strength.map_or(strength, |key_strength| strength.min(key_strength)))
+}
+
+/// Classification of an OID by a [`Policy`]
+///
+/// Variants are ordered from the most to the least acceptable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PolicyStatus {
+    /// The OID can be used
+    Allowed,
+    /// The OID can still be accepted, but should be replaced
+    Deprecated,
+    /// The OID must not be used
+    Forbidden,
+}
+
+impl fmt::Display for PolicyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            PolicyStatus::Allowed => "allowed",
+            PolicyStatus::Deprecated => "deprecated",
+            PolicyStatus::Forbidden => "forbidden",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The result of the evaluation of an OID by a [`Policy`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicyVerdict {
+    status: PolicyStatus,
+    reason: Cow<'static, str>,
+}
+
+impl PolicyVerdict {
+    fn allowed() -> Self {
+        PolicyVerdict {
+            status: PolicyStatus::Allowed,
+            reason: Cow::Borrowed(""),
+        }
+    }
+
+    // Keep the most severe status, and the first reason given for it
+    fn update<S: Into<Cow<'static, str>>>(&mut self, status: PolicyStatus, reason: S) {
+        if status > self.status {
+            self.status = status;
+            self.reason = reason.into();
+        }
+    }
+
+    /// Get the status of the OID
+    #[inline]
+    pub fn status(&self) -> PolicyStatus {
+        self.status
+    }
+
+    /// Get the reason of the status (empty if the OID is allowed)
+    #[inline]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns `true` if the OID is allowed
+    #[inline]
+    pub fn is_allowed(&self) -> bool {
+        self.status == PolicyStatus::Allowed
+    }
+}
+
+impl fmt::Display for PolicyVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.reason.is_empty() {
+            write!(f, "{}", self.status)
+        } else {
+            write!(f, "{}: {}", self.status, self.reason)
+        }
+    }
+}
+
+/// A security policy, classifying OIDs as allowed, deprecated or forbidden
+///
+/// OIDs are evaluated using:
+/// - their security strength (see [`security_strength`]): OIDs below the minimum strength are
+///   forbidden, and OIDs below the recommended strength are deprecated
+/// - their status in the registry (see [`OidStatus`](crate::OidStatus)): deprecated or obsolete
+///   OIDs get the status configured for obsolete OIDs
+/// - their kind: signature and public key algorithms, and elliptic curves, get the status
+///   configured for classical (not post-quantum) public key algorithms. All public key
+///   algorithms of the bundled sets are classical.
+/// - explicit overrides, which take precedence over all other rules
+///
+/// OIDs with an unknown strength (for ex. `rsaEncryption`, where the strength depends on the
+/// size of the key) are only evaluated using the other rules.
+///
+/// ```rust
+/// use oid_registry::{OidRegistry, Policy};
+///
+/// # #[cfg(feature = "registry")] {
+/// let registry = OidRegistry::default().with_all_features();
+/// for (oid, entry, verdict) in Policy::modern().audit(&registry) {
+///     println!("{} ({}): {}", entry.sn(), oid, verdict);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Policy {
+    name: Cow<'static, str>,
+    forbidden_below: usize,
+    deprecated_below: usize,
+    obsolete: PolicyStatus,
+    classical_public_key: PolicyStatus,
+    overrides: Vec<(Oid<'static>, PolicyStatus, Cow<'static, str>)>,
+}
+
+impl Policy {
+    /// Create a new policy, allowing all OIDs
+    pub fn new<S: Into<Cow<'static, str>>>(name: S) -> Self {
+        Policy {
+            name: name.into(),
+            forbidden_below: 0,
+            deprecated_below: 0,
+            obsolete: PolicyStatus::Allowed,
+            classical_public_key: PolicyStatus::Allowed,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Policy for new deployments
+    ///
+    /// Algorithms with a strength below 112 bits (for ex. MD5, SHA-1, RC4, 2-key Triple DES) are
+    /// forbidden, and below 128 bits (for ex. SHA-224, 3-key Triple DES) are deprecated. Obsolete
+    /// OIDs are deprecated.
+    pub fn modern() -> Self {
+        Policy::new("modern")
+            .forbid_below(112)
+            .deprecate_below(128)
+            .with_obsolete_status(PolicyStatus::Deprecated)
+    }
+
+    /// Policy for interoperability with legacy systems
+    ///
+    /// Only broken or very weak algorithms (strength below 56 bits, for ex. MD2, MD5, RC4, 40-bit
+    /// ciphers) are forbidden. Algorithms with a strength below 112 bits (for ex. SHA-1) and
+    /// obsolete OIDs are deprecated.
+    pub fn legacy_compatible() -> Self {
+        Policy::new("legacy-compatible")
+            .forbid_below(56)
+            .deprecate_below(112)
+            .with_obsolete_status(PolicyStatus::Deprecated)
+    }
+
+    /// Policy following the Commercial National Security Algorithm Suite 2.0 (CNSA 2.0)
+    ///
+    /// Algorithms with a strength below 192 bits are forbidden (CNSA 2.0 requires AES-256 and
+    /// SHA-384 or SHA-512). Classical public key algorithms are deprecated, since they must be
+    /// replaced by post-quantum algorithms (ML-KEM, ML-DSA). Obsolete OIDs are forbidden.
+    pub fn cnsa2() -> Self {
+        Policy::new("CNSA 2.0")
+            .forbid_below(192)
+            .with_obsolete_status(PolicyStatus::Forbidden)
+            .with_classical_public_key_status(PolicyStatus::Deprecated)
+    }
+
+    /// Forbid algorithms with a security strength (in bits) below `bits`
+    pub fn forbid_below(mut self, bits: usize) -> Self {
+        self.forbidden_below = bits;
+        self
+    }
+
+    /// Deprecate algorithms with a security strength (in bits) below `bits`
+    pub fn deprecate_below(mut self, bits: usize) -> Self {
+        self.deprecated_below = bits;
+        self
+    }
+
+    /// Set the status of deprecated or obsolete OIDs
+    pub fn with_obsolete_status(mut self, status: PolicyStatus) -> Self {
+        self.obsolete = status;
+        self
+    }
+
+    /// Set the status of classical (not post-quantum) public key algorithms
+    pub fn with_classical_public_key_status(mut self, status: PolicyStatus) -> Self {
+        self.classical_public_key = status;
+        self
+    }
+
+    /// Set the status of an OID, overriding all other rules
+    pub fn with_override<S: Into<Cow<'static, str>>>(mut self, oid: Oid<'static>, status: PolicyStatus, reason: S) -> Self {
+        self.overrides.retain(|(o, _, _)| o != &oid);
+        self.overrides.push((oid, status, reason.into()));
+        self
+    }
+
+    /// Get the name of this policy
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Classify an OID, using its registry entry
+    ///
+    /// The key of signature algorithms is not taken into account, see [`security_strength`].
+    pub fn evaluate(&self, oid: &Oid, entry: &OidEntry) -> PolicyVerdict {
+        if let Some((_, status, reason)) = self.overrides.iter().find(|(o, _, _)| o == oid) {
+            return PolicyVerdict {
+                status: *status,
+                reason: reason.clone(),
+            };
+        }
+        let mut verdict = PolicyVerdict::allowed();
+        match security_strength(oid) {
+            Some(0) if self.forbidden_below > 0 => verdict.update(PolicyStatus::Forbidden, "algorithm is broken"),
+            Some(strength) if strength < self.forbidden_below => verdict.update(
+                PolicyStatus::Forbidden,
+                format!(
+                    "security strength of {} bits is below the minimum of {} bits",
+                    strength, self.forbidden_below
+                ),
+            ),
+            Some(strength) if strength < self.deprecated_below => verdict.update(
+                PolicyStatus::Deprecated,
+                format!(
+                    "security strength of {} bits is below the recommended {} bits",
+                    strength, self.deprecated_below
+                ),
+            ),
+            _ => (),
+        }
+        if entry.status().is_deprecated() {
+            verdict.update(self.obsolete, format!("OID is {}", entry.status()));
+        }
+        if matches!(
+            entry.kind(),
+            OidKind::SignatureAlgorithm | OidKind::PublicKeyAlgorithm | OidKind::EllipticCurve
+        ) {
+            verdict.update(self.classical_public_key, "classical public key algorithm, not quantum-resistant");
+        }
+        verdict
+    }
+
+    /// Classify all entries of a registry, and return the entries which are not allowed
+    ///
+    /// Entries are sorted by decreasing severity, then by OID.
+    pub fn audit<'r, 'a>(&self, registry: &'r OidRegistry<'a>) -> Vec<(&'r Oid<'a>, &'r OidEntry, PolicyVerdict)> {
+        let mut v: Vec<_> = registry
+            .iter()
+            .map(|(oid, entry)| (oid, entry, self.evaluate(oid, entry)))
+            .filter(|(_, _, verdict)| !verdict.is_allowed())
+            .collect();
+        v.sort_by(|a, b| b.2.status.cmp(&a.2.status).then_with(|| a.0.as_bytes().cmp(b.0.as_bytes())));
+        v
+    }
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use crate::OidStatus;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_policy() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.3.4), ("a", "A"));
+        registry.insert(oid!(1.2.3.5), OidEntry::new("b", "B").with_status(OidStatus::Obsolete));
+        registry.insert(oid!(1.2.3.6), OidEntry::new("c", "C").with_kind(OidKind::SignatureAlgorithm));
+
+        let audit = Policy::modern().audit(&registry);
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].0, &oid!(1.2.3.5));
+        assert_eq!(audit[0].2.status(), PolicyStatus::Deprecated);
+        assert_eq!(audit[0].2.reason(), "OID is obsolete");
+
+        let audit = Policy::cnsa2().audit(&registry);
+        assert_eq!(audit.len(), 2);
+        assert_eq!(audit[0].2.status(), PolicyStatus::Forbidden);
+        assert_eq!(audit[1].0, &oid!(1.2.3.6));
+
+        let policy = Policy::new("custom").with_override(oid!(1.2.3.4), PolicyStatus::Forbidden, "not supported");
+        let verdict = policy.evaluate(&oid!(1.2.3.4), registry.get(&oid!(1.2.3.4)).unwrap());
+        assert_eq!(verdict.to_string(), "forbidden: not supported");
+        assert!(Policy::new("empty").audit(&registry).is_empty());
+
+        #[cfg(all(feature = "registry", feature = "crypto"))]
+        {
+            use crate::*;
+
+            let registry = OidRegistry::default().with_all_crypto();
+            let status = |policy: &Policy, oid: &Oid| policy.evaluate(oid, registry.get(oid).unwrap()).status();
+
+            // signatures are rated by their hash, bounded by the key type when it is fixed
+            #[cfg(feature = "x509")]
+            assert_eq!(security_strength(&OID_SIG_ED25519), Some(128));
+            assert_eq!(security_strength(&OID_SIG_ECDSA_WITH_SHA512), Some(256));
+            assert_eq!(security_strength(&OID_PKCS1_RSAENCRYPTION), None);
+
+            let modern = Policy::modern();
+            assert_eq!(status(&modern, &OID_PKCS1_MD2WITHRSAENC), PolicyStatus::Forbidden);
+            assert_eq!(status(&modern, &OID_PKCS1_SHA1WITHRSA), PolicyStatus::Forbidden);
+            assert_eq!(status(&modern, &OID_PKCS12_PBE_SHA1_40RC4), PolicyStatus::Forbidden);
+            assert_eq!(status(&modern, &OID_PKCS12_PBE_SHA1_3K_3DES_CBC), PolicyStatus::Deprecated);
+            assert_eq!(status(&modern, &OID_PKCS1_SHA256WITHRSA), PolicyStatus::Allowed);
+            assert_eq!(status(&modern, &OID_PKCS1_RSAENCRYPTION), PolicyStatus::Allowed);
+
+            let legacy = Policy::legacy_compatible();
+            assert_eq!(status(&legacy, &OID_PKCS1_MD5WITHRSAENC), PolicyStatus::Forbidden);
+            assert_eq!(status(&legacy, &OID_PKCS1_SHA1WITHRSA), PolicyStatus::Deprecated);
+            assert_eq!(status(&legacy, &OID_PKCS1_SHA256WITHRSA), PolicyStatus::Allowed);
+
+            let cnsa = Policy::cnsa2();
+            assert_eq!(status(&cnsa, &OID_NIST_ENC_AES256_CBC), PolicyStatus::Allowed);
+            assert_eq!(status(&cnsa, &OID_NIST_HASH_SHA384), PolicyStatus::Allowed);
+            assert_eq!(status(&cnsa, &OID_NIST_HASH_SHA256), PolicyStatus::Forbidden);
+            assert_eq!(status(&cnsa, &OID_SIG_ECDSA_WITH_SHA384), PolicyStatus::Deprecated);
+            assert_eq!(status(&cnsa, &OID_EC_P256), PolicyStatus::Forbidden);
+
+            assert_eq!(security_strength(&OID_NIST_HASH_SHA512), Some(256));
+            assert!(security_strength(&OID_PKCS1_RSAENCRYPTION).is_none());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Normalized name resolution
+
+use crate::{Oid, OidEntry, OidRegistry, OidRegistryError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Quality of a match when resolving a name
+///
+/// Variants are ordered from the weakest to the best match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchQuality {
+    /// The names are equal after ignoring case and `-`/`_` separators
+    Normalized,
+    /// The names are equal, ignoring case
+    CaseInsensitive,
+    /// The names are equal
+    Exact,
+}
+
+impl<'a> OidRegistry<'a> {
+    /// Resolve a name to the matching entries, ignoring case and separators
+    ///
+    /// Short names are compared to `name` case-insensitively and ignoring `-` and `_`, so
+    /// `ecdsa_with_sha256` matches `ecdsa-with-SHA256`. All candidates are returned, with the
+    /// quality of the match, best matches first.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::{MatchQuality, OidRegistry};
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.2.840.10045.2.1), ("id-ecPublicKey", "Elliptic curve public key"));
+    ///
+    /// let candidates = registry.resolve_name("ID_ECPUBLICKEY");
+    /// assert_eq!(candidates.len(), 1);
+    /// assert_eq!(candidates[0].2, MatchQuality::Normalized);
+    /// ```
+    pub fn resolve_name(&self, name: &str) -> Vec<(&Oid<'a>, &OidEntry, MatchQuality)> {
+        let lowercase = name.to_lowercase();
+        let normalized = normalize_name(name);
+        let mut v = Vec::new();
+        for (sn, oids) in &self.sn_index {
+            let quality = if sn == name {
+                MatchQuality::Exact
+            } else if sn.to_lowercase() == lowercase {
+                MatchQuality::CaseInsensitive
+            } else if normalize_name(sn) == normalized {
+                MatchQuality::Normalized
+            } else {
+                continue;
+            };
+            v.extend(oids.iter().filter_map(|oid| self.get_pair(oid)).map(|(oid, e)| (oid, e, quality)));
+        }
+        v.sort_by(|a, b| {
+            b.2.cmp(&a.2)
+                .then_with(|| a.1.sn().cmp(b.1.sn()))
+                .then_with(|| a.0.as_bytes().cmp(b.0.as_bytes()))
+        });
+        v
+    }
+
+    /// Resolve a name to a unique entry, ignoring case and separators
+    ///
+    /// This is the strict version of [`resolve_name`](OidRegistry::resolve_name): only the best
+    /// matches are considered, and an `AmbiguousName` error is returned if there is more than one.
+    /// Returns `Ok(None)` if no entry matches.
+    pub fn resolve_name_strict(&self, name: &str) -> Result<Option<(&Oid<'a>, &OidEntry)>, OidRegistryError> {
+        let candidates = self.resolve_name(name);
+        let best = match candidates.first() {
+            Some(c) => c.2,
+            None => return Ok(None),
+        };
+        let count = candidates.iter().take_while(|c| c.2 == best).count();
+        if count > 1 {
+            return Err(OidRegistryError::AmbiguousName {
+                name: name.to_string(),
+                count,
+            });
+        }
+        Ok(Some((candidates[0].0, candidates[0].1)))
+    }
+}
+
+// Lowercase name, without `-` and `_` separators
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|&c| c != '-' && c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[rustfmt::skip::macros(oid)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_resolve_name() {
+        let mut registry = OidRegistry::default();
+        registry.insert(oid!(1.2.840.10045.4.3.2), ("ecdsa-with-SHA256", "ECDSA with SHA256"));
+        registry.insert(oid!(2.16.840.1.101.3.4.2.1), ("sha256", "SHA256"));
+        registry.insert(oid!(1.2.3.4), ("SHA-256", "Another SHA256"));
+
+        let candidates = registry.resolve_name("ecdsa_with_sha256");
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].1.sn(), "ecdsa-with-SHA256");
+        assert_eq!(candidates[0].2, MatchQuality::Normalized);
+
+        let candidates = registry.resolve_name("sha256");
+        let v: Vec<_> = candidates.iter().map(|c| (c.1.sn(), c.2)).collect();
+        assert_eq!(v, [("sha256", MatchQuality::Exact), ("SHA-256", MatchQuality::Normalized)]);
+        assert!(registry.resolve_name("sha384").is_empty());
+
+        // strict mode only considers the best matches
+        let (oid, _) = registry.resolve_name_strict("sha256").unwrap().expect("not found");
+        assert_eq!(oid, &oid!(2.16.840.1.101.3.4.2.1));
+        assert!(registry.resolve_name_strict("Sha_256").is_err());
+        assert!(registry.resolve_name_strict("sha384").unwrap().is_none());
+    }
+}
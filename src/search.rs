@@ -0,0 +1,127 @@
+//! Full-text search over short names and descriptions
+
+use crate::{Oid, OidEntry, OidRegistry};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+impl<'a> OidRegistry<'a> {
+    /// Search entries matching all the words of `query`, best matches first
+    ///
+    /// Short names and descriptions are split into lowercase words (short names are also split on
+    /// case changes, so `sha256WithRSAEncryption` contains the word `rsa`). Every word of the
+    /// query must be a prefix of at least one word of the entry. Matches on short names rank
+    /// higher than matches on descriptions, and complete words rank higher than prefixes.
+    ///
+    /// ```rust
+    /// use asn1_rs::oid;
+    /// use oid_registry::OidRegistry;
+    ///
+    /// let mut registry = OidRegistry::default();
+    /// registry.insert(oid!(1.2.840.10045.3.1.7), ("prime256v1", "P-256 elliptic curve parameter"));
+    /// registry.insert(oid!(1.3.132.0.34), ("secp384r1", "P-384 elliptic curve parameter"));
+    /// registry.insert(oid!(1.2.840.113549.1.9.5), ("signing-time", "id-signingTime"));
+    ///
+    /// assert_eq!(registry.search("curve").len(), 2);
+    /// assert_eq!(registry.search("ellip p-384").len(), 1);
+    /// assert_eq!(registry.search("signing time").len(), 1);
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<(&Oid<'a>, &OidEntry)> {
+        let terms = tokenize(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let mut results: Vec<_> = self
+            .iter()
+            .filter_map(|(oid, entry)| score_entry(entry, &terms).map(|score| (score, oid, entry)))
+            .collect();
+        results.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| a.2.sn().cmp(b.2.sn()))
+                .then_with(|| a.1.as_bytes().cmp(b.1.as_bytes()))
+        });
+        results.into_iter().map(|(_, oid, entry)| (oid, entry)).collect()
+    }
+}
+
+// Return the score of the entry, or `None` if any term does not match
+fn score_entry(entry: &OidEntry, terms: &[String]) -> Option<u32> {
+    let sn_words = tokenize(entry.sn());
+    let desc_words = tokenize(entry.description());
+    terms.iter().try_fold(0, |acc, term| {
+        let score = if sn_words.contains(term) {
+            4
+        } else if sn_words.iter().any(|w| w.starts_with(term.as_str())) {
+            3
+        } else if desc_words.contains(term) {
+            2
+        } else if desc_words.iter().any(|w| w.starts_with(term.as_str())) {
+            1
+        } else {
+            return None;
+        };
+        Some(acc + score)
+    })
+}
+
+// Split text into lowercase words
+//
+// Words are separated by non-alphanumeric characters, and by case changes (`camelCase` or
+// `RSAEncryption`). Digits stay attached to the preceding letters.
+fn tokenize(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in text.split(|c: char| !c.is_alphanumeric()).filter(|s| !s.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut current = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+                if !prev.is_uppercase() || next_is_lower {
+                    words.push(core::mem::take(&mut current));
+                }
+            }
+            current.extend(c.to_lowercase());
+        }
+        words.push(current);
+    }
+    words
+}
+
+#[rustfmt::skip::macros(oid)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("sha256WithRSAEncryption"), ["sha256", "with", "rsa", "encryption"]);
+        assert_eq!(tokenize("id-ad-caIssuers"), ["id", "ad", "ca", "issuers"]);
+        assert_eq!(tokenize("GOST R 34.10-2012"), ["gost", "r", "34", "10", "2012"]);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut registry = OidRegistry::default();
+        registry.insert(
+            oid!(1.2.840.113549.1.1.11),
+            ("sha256WithRSAEncryption", "SHA256 with RSA encryption"),
+        );
+        registry.insert(oid!(1.2.840.113549.1.1.1), ("rsaEncryption", "RSAES-PKCS1-v1_5 encryption scheme"));
+        registry.insert(
+            oid!(1.3.6.1.5.5.7.48.3),
+            ("id-ad-timestamping", "PKIX Access Descriptor Timestamping"),
+        );
+        registry.insert(
+            oid!(1.3.6.1.4.1.11129.2.4.2),
+            ("ctSCTList", "Certificate Transparency Signed Certificate Timestamp List"),
+        );
+
+        let sns = |query: &str| -> Vec<String> { registry.search(query).iter().map(|(_, e)| e.sn().to_string()).collect() };
+        assert_eq!(sns("rsa"), ["rsaEncryption", "sha256WithRSAEncryption"]);
+        assert_eq!(sns("rsa sha256"), ["sha256WithRSAEncryption"]);
+        assert_eq!(sns("timestamp"), ["id-ad-timestamping", "ctSCTList"]);
+        assert!(sns("timestamp rsa").is_empty());
+        assert!(sns("").is_empty());
+    }
+}
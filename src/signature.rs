@@ -0,0 +1,84 @@
+//! Relations between signature algorithms, hash algorithms and public key types
+
+use crate::static_registry::SIGNATURE_COMPONENTS;
+use crate::Oid;
+
+/// Get the hash algorithm and the public key type of a signature algorithm
+///
+/// Returns `None` if the signature algorithm is unknown (or the features declaring the signature,
+/// hash and key type are not all enabled), or if it cannot be decomposed: for ex. the parameters of RSASSA-PSS carry the hash algorithm, so
+/// `OID_PKCS1_RSASSAPSS` has no components.
+///
+/// ```rust
+/// use oid_registry::signature_components;
+///
+/// # #[cfg(all(feature = "pkcs1", feature = "nist_algs"))] {
+/// let (hash, key) = signature_components(&oid_registry::OID_PKCS1_SHA256WITHRSA).expect("unknown signature");
+/// assert_eq!(hash, &oid_registry::OID_NIST_HASH_SHA256);
+/// assert_eq!(key, &oid_registry::OID_PKCS1_RSAENCRYPTION);
+/// # }
+/// ```
+pub fn signature_components(oid: &Oid) -> Option<(&'static Oid<'static>, &'static Oid<'static>)> {
+    SIGNATURE_COMPONENTS
+        .iter()
+        .find(|(sig, _, _)| sig == oid)
+        .map(|(_, hash, key)| (hash, key))
+}
+
+/// Get the signature algorithm combining a hash algorithm and a public key type
+///
+/// If several signature OIDs match (for ex. the OIW and PKCS #1 OIDs for SHA-1 with RSA), the
+/// current OID is preferred to the obsolete ones.
+pub fn signature_oid_for(hash: &Oid, key_type: &Oid) -> Option<&'static Oid<'static>> {
+    SIGNATURE_COMPONENTS
+        .iter()
+        .find(|(_, h, k)| h == hash && k == key_type)
+        .map(|(sig, _, _)| sig)
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(oid)]
+mod tests {
+    use super::*;
+    use asn1_rs::oid;
+
+    #[test]
+    fn test_signature_components() {
+        assert!(signature_components(&oid!(1.2.3.4)).is_none());
+        assert!(signature_oid_for(&oid!(1.2.3), &oid!(1.2.4)).is_none());
+
+        #[cfg(all(feature = "pkcs1", feature = "x962", feature = "x509", feature = "nist_algs"))]
+        {
+            use crate::*;
+
+            let (hash, key) = signature_components(&OID_SIG_ECDSA_WITH_SHA384).unwrap();
+            assert_eq!((hash, key), (&OID_NIST_HASH_SHA384, &OID_KEY_TYPE_EC_PUBLIC_KEY));
+            let (hash, key) = signature_components(&OID_SIG_GOST_R3410_2012_256).unwrap();
+            assert_eq!((hash, key), (&OID_HASH_GOST_R3411_2012_256, &OID_KEY_TYPE_GOST_R3410_2012_256));
+            assert!(signature_components(&OID_PKCS1_RSASSAPSS).is_none());
+
+            // reverse lookup prefers current OIDs
+            assert_eq!(
+                signature_oid_for(&OID_HASH_SHA1, &OID_PKCS1_RSAENCRYPTION),
+                Some(&OID_PKCS1_SHA1WITHRSA)
+            );
+            assert_eq!(
+                signature_oid_for(&OID_HASH_MD5, &OID_PKCS1_RSAENCRYPTION),
+                Some(&OID_PKCS1_MD5WITHRSAENC)
+            );
+            assert_eq!(signature_oid_for(&OID_HASH_SHA1, &OID_KEY_TYPE_DSA), Some(&OID_SIG_DSA_WITH_SHA1));
+
+            // every signature algorithm can be decomposed, except RSASSA-PSS
+            let registry = StaticOidRegistry::new();
+            for (oid, _) in registry.iter_by_kind(OidKind::SignatureAlgorithm) {
+                if oid == &OID_PKCS1_RSASSAPSS {
+                    continue;
+                }
+                let (hash, key) = signature_components(oid).expect("no signature components");
+                assert_eq!(registry.get(hash).map(|e| e.kind()), Some(OidKind::HashAlgorithm));
+                assert!(registry.contains(key));
+                assert_eq!(signature_components(signature_oid_for(hash, key).unwrap()), Some((hash, key)));
+            }
+        }
+    }
+}
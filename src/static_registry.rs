@@ -0,0 +1,135 @@
+//! Read-only registry, generated at compile-time
+
+use crate::{Oid, OidEntry, OidKind};
+use alloc::format;
+use alloc::string::String;
+
+/// A table of known OIDs for one feature, generated by the build script
+///
+/// Entries are sorted by the DER encoding of the OID, and `by_sn` contains the names (short names
+/// and aliases) and the index of the corresponding entry, sorted by name.
+#[derive(Debug)]
+pub(crate) struct StaticTable {
+    pub(crate) entries: &'static [(Oid<'static>, OidEntry)],
+    pub(crate) by_sn: &'static [(&'static str, u16)],
+}
+
+impl StaticTable {
+    fn get_by_der(&self, der: &[u8]) -> Option<&'static (Oid<'static>, OidEntry)> {
+        let entries = self.entries;
+        let idx = entries.binary_search_by(|(o, _)| o.as_bytes().cmp(der)).ok()?;
+        Some(&entries[idx])
+    }
+
+    fn iter_by_sn<'s>(&self, sn: &'s str) -> impl Iterator<Item = &'static (Oid<'static>, OidEntry)> + 's {
+        let (by_sn, entries) = (self.by_sn, self.entries);
+        let start = by_sn.partition_point(|(name, _)| *name < sn);
+        by_sn[start..]
+            .iter()
+            .take_while(move |(name, _)| *name == sn)
+            .map(move |(_, idx)| &entries[*idx as usize])
+    }
+}
+
+/// Read-only registry of the known OIDs of all enabled features
+///
+/// Unlike [`OidRegistry`](crate::OidRegistry), this registry is generated at compile-time as
+/// static tables: it has no initialization cost, and lookups do not allocate. OIDs are looked up
+/// using a binary search on their DER encoding, and short names using a binary search on a
+/// sorted index.
+///
+/// This registry cannot be modified. To add entries, populate an `OidRegistry` instead.
+///
+/// ```rust
+/// use oid_registry::StaticOidRegistry;
+///
+/// let registry = StaticOidRegistry::new();
+/// # #[cfg(feature = "pkcs1")] {
+/// let entry = registry.get(&oid_registry::OID_PKCS1_SHA256WITHRSA).expect("not found");
+/// assert_eq!(entry.sn(), "sha256WithRSAEncryption");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StaticOidRegistry {
+    tables: &'static [&'static StaticTable],
+}
+
+impl StaticOidRegistry {
+    /// Get the registry of the known OIDs of all enabled features
+    pub const fn new() -> Self {
+        StaticOidRegistry { tables: STATIC_TABLES }
+    }
+
+    /// Returns a reference to the registry entry, if found for this OID.
+    pub fn get(&self, oid: &Oid) -> Option<&'static OidEntry> {
+        self.get_by_der(oid.as_bytes())
+    }
+
+    /// Returns a reference to the registry entry, if found for the OID with this DER encoding.
+    ///
+    /// See [`OidRegistry::get_by_der`](crate::OidRegistry::get_by_der).
+    pub fn get_by_der(&self, der: &[u8]) -> Option<&'static OidEntry> {
+        self.tables.iter().find_map(|table| table.get_by_der(der)).map(|(_, e)| e)
+    }
+
+    /// Returns `true` if the registry contains an entry for this OID
+    pub fn contains(&self, oid: &Oid) -> bool {
+        self.get(oid).is_some()
+    }
+
+    /// Return an Iterator over references to the `(Oid, OidEntry)` key/value pairs
+    ///
+    /// Entries are sorted by feature, then by DER encoding of the OID.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Oid<'static>, &'static OidEntry)> {
+        self.tables.iter().flat_map(|table| table.entries.iter().map(|(oid, e)| (oid, e)))
+    }
+
+    /// Return the `(Oid, OidEntry)` key/value pairs, matching a short name or an alias
+    pub fn iter_by_sn<'s>(&self, sn: &'s str) -> impl Iterator<Item = (&'static Oid<'static>, &'static OidEntry)> + 's {
+        self.tables
+            .iter()
+            .flat_map(move |table| table.iter_by_sn(sn))
+            .map(|(oid, e)| (oid, e))
+    }
+
+    /// Return the `(Oid, OidEntry)` key/value pairs of a given kind
+    pub fn iter_by_kind(&self, kind: OidKind) -> impl Iterator<Item = (&'static Oid<'static>, &'static OidEntry)> {
+        self.iter().filter(move |(_, entry)| entry.kind() == kind)
+    }
+}
+
+impl Default for StaticOidRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Format a OID to a `String`, using the static registry to get the short name if present.
+///
+/// See [`format_oid`](crate::format_oid).
+pub fn format_oid_static(oid: &Oid, registry: &StaticOidRegistry) -> String {
+    match registry.get(oid) {
+        Some(entry) => format!("{} ({})", entry.sn(), oid),
+        None => format!("{}", oid),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/oid_tables.rs"));
+
+#[cfg(all(test, feature = "registry"))]
+mod tests {
+    use super::*;
+    use crate::OidRegistry;
+
+    #[test]
+    fn test_static_registry() {
+        let registry = StaticOidRegistry::new();
+        let dynamic = OidRegistry::default().with_all_features();
+        assert_eq!(registry.iter().count(), dynamic.len());
+        for (oid, entry) in dynamic.iter() {
+            assert_eq!(registry.get(oid), Some(entry));
+            assert!(registry.iter_by_sn(entry.sn()).any(|(o, _)| o == oid));
+        }
+        assert!(registry.iter_by_sn("notAShortName").next().is_none());
+    }
+}
@@ -0,0 +1,139 @@
+//! Arc tree, used to navigate the hierarchy of registered OIDs
+
+use alloc::collections::btree_map::Values;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use asn1_rs::Oid;
+
+/// A node of the arc tree.
+///
+/// Each node corresponds to one arc (sub-identifier) of an OID. The node is said to be
+/// *registered* if an OID ending at this arc was inserted in the tree.
+#[derive(Debug, Default)]
+pub(crate) struct ArcNode<'a> {
+    oid: Option<Oid<'a>>,
+    children: BTreeMap<u64, ArcNode<'a>>,
+}
+
+impl<'a> ArcNode<'a> {
+    /// Register `oid` at the position given by `arcs`
+    pub(crate) fn insert<I: Iterator<Item = u64>>(&mut self, arcs: I, oid: Oid<'a>) {
+        let mut node = self;
+        for arc in arcs {
+            node = node.children.entry(arc).or_default();
+        }
+        node.oid = Some(oid);
+    }
+
+    /// Unregister the OID at the position given by `arcs`, pruning the nodes left empty
+    pub(crate) fn remove<I: Iterator<Item = u64>>(&mut self, mut arcs: I) -> Option<Oid<'a>> {
+        match arcs.next() {
+            None => self.oid.take(),
+            Some(arc) => {
+                let child = self.children.get_mut(&arc)?;
+                let oid = child.remove(arcs);
+                if child.oid.is_none() && child.children.is_empty() {
+                    self.children.remove(&arc);
+                }
+                oid
+            }
+        }
+    }
+
+    /// Get the OID registered at this node, if any
+    #[inline]
+    pub(crate) fn oid(&self) -> Option<&Oid<'a>> {
+        self.oid.as_ref()
+    }
+
+    /// Find the node at the position given by `arcs`, registered or not
+    pub(crate) fn find<I: Iterator<Item = u64>>(&self, arcs: I) -> Option<&ArcNode<'a>> {
+        let mut node = self;
+        for arc in arcs {
+            node = node.children.get(&arc)?;
+        }
+        Some(node)
+    }
+
+    /// Find the deepest registered node on the path to `arcs` (including the last node)
+    ///
+    /// Return the registered OID and the number of arcs matched.
+    pub(crate) fn closest<I: Iterator<Item = u64>>(&self, arcs: I) -> Option<(&Oid<'a>, usize)> {
+        let mut closest = None;
+        let mut node = self;
+        for (depth, arc) in arcs.enumerate() {
+            node = match node.children.get(&arc) {
+                Some(n) => n,
+                None => break,
+            };
+            if let Some(oid) = &node.oid {
+                closest = Some((oid, depth + 1));
+            }
+        }
+        closest
+    }
+
+    /// Return the registered OIDs on the path to `arcs` (excluding the last node), closest first
+    pub(crate) fn ancestors<I: Iterator<Item = u64>>(&self, arcs: I) -> Vec<&Oid<'a>> {
+        let mut v = Vec::new();
+        let mut node = self;
+        let mut arcs = arcs.peekable();
+        while let Some(arc) = arcs.next() {
+            node = match node.children.get(&arc) {
+                Some(n) => n,
+                None => break,
+            };
+            if arcs.peek().is_none() {
+                break;
+            }
+            if let Some(oid) = &node.oid {
+                v.push(oid);
+            }
+        }
+        v.reverse();
+        v
+    }
+
+    /// Iterate over the registered nodes below this node, in arc order
+    ///
+    /// If `children_only` is true, the iteration does not go below registered nodes, so only the
+    /// closest registered descendants are returned.
+    pub(crate) fn descendants(&self, children_only: bool) -> Descendants<'_, 'a> {
+        Descendants {
+            stack: vec![self.children.values()],
+            children_only,
+        }
+    }
+}
+
+/// Depth-first iterator over the registered nodes of an arc tree
+#[derive(Debug)]
+pub(crate) struct Descendants<'t, 'a> {
+    stack: Vec<Values<'t, u64, ArcNode<'a>>>,
+    children_only: bool,
+}
+
+impl<'t, 'a> Iterator for Descendants<'t, 'a> {
+    type Item = &'t Oid<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(iter) = self.stack.last_mut() {
+            match iter.next() {
+                Some(node) => match &node.oid {
+                    Some(oid) => {
+                        if !self.children_only {
+                            self.stack.push(node.children.values());
+                        }
+                        return Some(oid);
+                    }
+                    None => self.stack.push(node.children.values()),
+                },
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+        None
+    }
+}